@@ -0,0 +1,80 @@
+/*!
+Dense fitness kernel benchmark.
+
+Purpose:
+    Times the built-in GLV reaction `n ⊙ (g + Vn)` against the scalar
+    indexed loop it replaced, for a range of species counts. Run with
+    `cargo bench --bench matvec`.
+*/
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use general_lotka_volterra_rs::solvers::dynamics::{Dynamics, GlvPopulation};
+use ndarray::{Array1, Array2};
+
+/// Species counts to time.
+const SIZES: [usize; 5] = [64, 256, 512, 1024, 2048];
+
+/// Minimum wall time per measurement.
+const TARGET: Duration = Duration::from_millis(300);
+
+/// The previous kernel: bounds-checked `Array2` indexing, one accumulator.
+fn scalar_reaction(v: &Array2<f64>, g: &Array1<f64>, u: &[f64], out: &mut [f64]) {
+    let d = u.len();
+    for i in 0..d {
+        let mut acc = g[i];
+        for j in 0..d {
+            acc += v[(i, j)] * u[j];
+        }
+        out[i] = u[i] * acc;
+    }
+}
+
+/// Mean seconds per call of `f`, repeated until `TARGET` has elapsed.
+fn time_per_call(mut f: impl FnMut()) -> f64 {
+    f();
+    let mut calls = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < TARGET {
+        f();
+        calls += 1;
+    }
+    start.elapsed().as_secs_f64() / calls as f64
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>14} {:>14} {:>9}",
+        "d", "scalar (µs)", "kernel (µs)", "speedup"
+    );
+    for d in SIZES {
+        let v = Array2::from_shape_fn((d, d), |(i, j)| {
+            ((i * 31 + j * 17) % 97) as f64 / 97.0 - 0.5
+        });
+        let g = Array1::from_shape_fn(d, |i| (i % 7) as f64 * 0.1);
+        let u: Vec<f64> = (0..d).map(|i| 1.0 + (i % 5) as f64 * 0.2).collect();
+        let mut out = vec![0.0; d];
+        let glv = GlvPopulation {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        };
+
+        let scalar = time_per_call(|| {
+            scalar_reaction(black_box(&v), black_box(&g), black_box(&u), &mut out);
+            black_box(&out);
+        });
+        let kernel = time_per_call(|| {
+            glv.reaction(black_box(&u), &mut out);
+            black_box(&out);
+        });
+
+        println!(
+            "{:>6} {:>14.2} {:>14.2} {:>8.2}x",
+            d,
+            scalar * 1e6,
+            kernel * 1e6,
+            scalar / kernel
+        );
+    }
+}
@@ -9,12 +9,13 @@ use std::io::Result;
 use std::path::Path;
 use std::sync::atomic::AtomicUsize;
 
+use general_lotka_volterra_rs::solvers::non_spatial::sde::{Calculus, SdeScheme};
+use general_lotka_volterra_rs::solvers::rng::RngSeed;
 use general_lotka_volterra_rs::solvers::spatial::rk4::{Boundary, Diffusion};
 use general_lotka_volterra_rs::solvers::termination::TerminationConfig;
 use general_lotka_volterra_rs::tasks::metadata::TaskOutcome;
 use ndarray::{Array1, Array2};
-use rand::rngs::SmallRng;
-use rand::{RngExt, SeedableRng};
+use rand::RngExt;
 
 // ---------------------------------------------------------------------------
 // Shared settings
@@ -26,6 +27,10 @@ pub const NUM_STRAINS: usize = 10;
 /// Total solver steps for every example. Output files are chunked automatically.
 pub const TOTAL_STEPS: usize = 10_000;
 
+/// Seed for random interaction matrices and noise streams. Rerunning an
+/// example with the same seed reproduces its output bit-for-bit.
+pub const SEED: u64 = 20_240_601;
+
 // ---------------------------------------------------------------------------
 // Shared Non-spatial settings
 // ---------------------------------------------------------------------------
@@ -45,6 +50,24 @@ pub const WELL_MIXED_DT: f64 = 0.005;
 /// Save one state sample every N solver steps for non-spatial examples.
 pub const NON_SPATIAL_SAVE_INTERVAL: usize = 500;
 
+/// Population cutoff for well-mixed GLV examples.
+pub const LV_WELL_MIXED_CUTOFF: f64 = 1e-6;
+
+/// Optional global carrying capacity for well-mixed GLV examples.
+pub const LV_WELL_MIXED_CARRYING_CAPACITY: Option<f64> = None;
+
+/// Initial population per species for well-mixed GLV examples.
+pub const LV_WELL_MIXED_INITIAL_POPULATION: f64 = 0.1;
+
+/// Growth rate shared by every species in well-mixed GLV examples.
+pub const LV_WELL_MIXED_GROWTH: f64 = 1.0;
+
+/// Self-limitation coefficient on the well-mixed GLV interaction diagonal.
+pub const LV_WELL_MIXED_SELF_LIMITATION: f64 = -1.0;
+
+/// Scale applied to random off-diagonal well-mixed GLV interactions.
+pub const LV_WELL_MIXED_PAIR_SCALE: f64 = 0.4;
+
 // ---------------------------------------------------------------------------
 // Shared Spatial settings
 // ---------------------------------------------------------------------------
@@ -81,9 +104,45 @@ pub const REPLICATOR_DEMOGRAPHIC_OUTPUT: &str = "output/replicator_demographic";
 /// Plot title and progress label for the demographic-noise example.
 pub const REPLICATOR_DEMOGRAPHIC_LABEL: &str = "replicator_demographic";
 
-/// Demographic Gaussian noise strength used after each deterministic step.
+/// Demographic Gaussian noise strength.
 pub const REPLICATOR_DEMOGRAPHIC_SIGMA: f64 = 0.1;
 
+/// SDE scheme for the simplex-centred demographic noise (Milstein is not
+/// offered for centred kinds).
+pub const REPLICATOR_DEMOGRAPHIC_SDE_SCHEME: SdeScheme = SdeScheme::EulerMaruyama;
+
+/// Demographic noise is read in the Itô sense.
+pub const REPLICATOR_DEMOGRAPHIC_CALCULUS: Calculus = Calculus::Ito;
+
+// ---------------------------------------------------------------------------
+// lv_deterministic
+// ---------------------------------------------------------------------------
+
+/// Output directory for the deterministic well-mixed GLV example.
+pub const LV_DETERMINISTIC_OUTPUT: &str = "output/lv_deterministic";
+
+/// Plot title and progress label for the deterministic well-mixed GLV example.
+pub const LV_DETERMINISTIC_LABEL: &str = "lv_deterministic";
+
+// ---------------------------------------------------------------------------
+// lv_demographic
+// ---------------------------------------------------------------------------
+
+/// Output directory for the demographic-noise well-mixed GLV example.
+pub const LV_DEMOGRAPHIC_OUTPUT: &str = "output/lv_demographic";
+
+/// Plot title and progress label for the demographic-noise GLV example.
+pub const LV_DEMOGRAPHIC_LABEL: &str = "lv_demographic";
+
+/// Population demographic noise strength.
+pub const LV_DEMOGRAPHIC_SIGMA: f64 = 0.05;
+
+/// SDE scheme for the diagonal population demographic noise.
+pub const LV_DEMOGRAPHIC_SDE_SCHEME: SdeScheme = SdeScheme::Milstein;
+
+/// Demographic noise is read in the Itô sense.
+pub const LV_DEMOGRAPHIC_CALCULUS: Calculus = Calculus::Ito;
+
 // ---------------------------------------------------------------------------
 // replicator_diffusive_deterministic
 // ---------------------------------------------------------------------------
@@ -152,6 +211,14 @@ pub fn replicator_demographic_output_path() -> &'static Path {
     Path::new(REPLICATOR_DEMOGRAPHIC_OUTPUT)
 }
 
+pub fn lv_deterministic_output_path() -> &'static Path {
+    Path::new(LV_DETERMINISTIC_OUTPUT)
+}
+
+pub fn lv_demographic_output_path() -> &'static Path {
+    Path::new(LV_DEMOGRAPHIC_OUTPUT)
+}
+
 pub fn replicator_diffusive_deterministic_output_path() -> &'static Path {
     Path::new(REPLICATOR_DIFFUSIVE_DETERMINISTIC_OUTPUT)
 }
@@ -188,6 +255,9 @@ pub fn run_replicator_demographic(progress_counter: Option<&AtomicUsize>) -> Res
         None,
         WELL_MIXED_CUTOFF,
         REPLICATOR_DEMOGRAPHIC_SIGMA,
+        REPLICATOR_DEMOGRAPHIC_SDE_SCHEME,
+        REPLICATOR_DEMOGRAPHIC_CALCULUS,
+        RngSeed::new(SEED),
         WELL_MIXED_DT,
         TOTAL_STEPS,
         NON_SPATIAL_SAVE_INTERVAL,
@@ -197,6 +267,42 @@ pub fn run_replicator_demographic(progress_counter: Option<&AtomicUsize>) -> Res
     )
 }
 
+pub fn run_lv_deterministic(progress_counter: Option<&AtomicUsize>) -> Result<TaskOutcome> {
+    general_lotka_volterra_rs::tasks::lv_deterministic::run(
+        &lv_well_mixed_interaction_matrix(),
+        Some(&lv_well_mixed_growth_vector()),
+        LV_WELL_MIXED_CUTOFF,
+        LV_WELL_MIXED_CARRYING_CAPACITY,
+        LV_WELL_MIXED_INITIAL_POPULATION,
+        WELL_MIXED_DT,
+        TOTAL_STEPS,
+        NON_SPATIAL_SAVE_INTERVAL,
+        lv_deterministic_output_path(),
+        progress_counter,
+        non_spatial_termination(),
+    )
+}
+
+pub fn run_lv_demographic(progress_counter: Option<&AtomicUsize>) -> Result<TaskOutcome> {
+    general_lotka_volterra_rs::tasks::lv_demographic::run(
+        &lv_well_mixed_interaction_matrix(),
+        Some(&lv_well_mixed_growth_vector()),
+        LV_WELL_MIXED_CUTOFF,
+        LV_WELL_MIXED_CARRYING_CAPACITY,
+        LV_WELL_MIXED_INITIAL_POPULATION,
+        LV_DEMOGRAPHIC_SIGMA,
+        LV_DEMOGRAPHIC_SDE_SCHEME,
+        LV_DEMOGRAPHIC_CALCULUS,
+        RngSeed::new(SEED),
+        WELL_MIXED_DT,
+        TOTAL_STEPS,
+        NON_SPATIAL_SAVE_INTERVAL,
+        lv_demographic_output_path(),
+        progress_counter,
+        non_spatial_termination(),
+    )
+}
+
 pub fn run_replicator_diffusive_deterministic(
     progress_counter: Option<&AtomicUsize>,
 ) -> Result<TaskOutcome> {
@@ -236,13 +342,30 @@ pub fn run_lv_diffusive_deterministic(
 }
 
 fn well_mixed_interaction_matrix() -> Array2<f64> {
-    let mut rng = SmallRng::from_rng(&mut rand::rng());
+    let mut rng = RngSeed::new(SEED).rng();
 
     Array2::from_shape_fn((NUM_STRAINS, NUM_STRAINS), |_| {
         rng.random_range(RANDOM_INTERACTION_MIN..=RANDOM_INTERACTION_MAX)
     })
 }
 
+fn lv_well_mixed_interaction_matrix() -> Array2<f64> {
+    let mut rng = RngSeed::new(SEED).rng();
+
+    Array2::from_shape_fn((NUM_STRAINS, NUM_STRAINS), |(i, j)| {
+        if i == j {
+            LV_WELL_MIXED_SELF_LIMITATION
+        } else {
+            LV_WELL_MIXED_PAIR_SCALE
+                * rng.random_range(RANDOM_INTERACTION_MIN..=RANDOM_INTERACTION_MAX)
+        }
+    })
+}
+
+fn lv_well_mixed_growth_vector() -> Array1<f64> {
+    Array1::from_elem(NUM_STRAINS, LV_WELL_MIXED_GROWTH)
+}
+
 fn replicator_diffusive_interaction_matrix() -> Array2<f64> {
     Array2::from_shape_fn((NUM_STRAINS, NUM_STRAINS), |(i, j)| {
         if i == j {
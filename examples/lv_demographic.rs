@@ -0,0 +1,20 @@
+/*!
+Demographic-noise GLV Cargo example.
+
+Purpose:
+    Builds a self-limited random GLV interaction matrix and runs a longer
+    population demographic-noise trajectory into `output/lv_demographic`.
+*/
+
+mod common;
+#[path = "common/constants.rs"]
+mod constants;
+
+fn main() {
+    common::run_and_render(
+        constants::LV_DEMOGRAPHIC_LABEL,
+        constants::TOTAL_STEPS,
+        constants::lv_demographic_output_path(),
+        constants::run_lv_demographic,
+    );
+}
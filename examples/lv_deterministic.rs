@@ -0,0 +1,21 @@
+/*!
+Deterministic GLV Cargo example.
+
+Purpose:
+    Builds a self-limited random GLV interaction matrix and runs a longer
+    deterministic well-mixed population trajectory into
+    `output/lv_deterministic`.
+*/
+
+mod common;
+#[path = "common/constants.rs"]
+mod constants;
+
+fn main() {
+    common::run_and_render(
+        constants::LV_DETERMINISTIC_LABEL,
+        constants::TOTAL_STEPS,
+        constants::lv_deterministic_output_path(),
+        constants::run_lv_deterministic,
+    );
+}
@@ -4,6 +4,9 @@ This script accepts an output directory, a signal directory, or a single signal
 JSON file. When given an output directory with a `signal/` child, it loads every
 numeric signal JSON in order, concatenates the full simulation in memory, and
 calls the plotter to produce `plot.png`.
+
+Samples are placed at their `physical_time`; output written before that key
+existed falls back to the integer step index in `time`.
 """
 from __future__ import annotations
 import json
@@ -16,6 +19,13 @@ except ImportError:
     from plotter import plot_frequency_and_heatmap
 
 
+def sample_time(sample: dict) -> float:
+    physical_time = sample.get("physical_time")
+    if physical_time is not None:
+        return float(physical_time)
+    return float(sample.get("time", 0))
+
+
 def load_json_samples(json_path: Path) -> Tuple[np.ndarray, np.ndarray]:
     with json_path.open() as f:
         j = json.load(f)
@@ -27,7 +37,7 @@ def load_json_samples(json_path: Path) -> Tuple[np.ndarray, np.ndarray]:
     times = []
     states = []
     for s in samples:
-        times.append(float(s.get("time", 0)))
+        times.append(sample_time(s))
         st = s.get("state")
         if st and "data" in st:
             data = st["data"]
@@ -42,6 +42,35 @@ class RenderFromOutputTests(unittest.TestCase):
         np.testing.assert_array_equal(t, np.array([0.0, 1.0]))
         np.testing.assert_allclose(nu, np.array([[0.25, 0.75], [0.5, 0.5]]))
 
+    @unittest.skipIf(np is None, "numpy is required for plotting tests")
+    def test_physical_time_is_preferred_over_step_index(self):
+        payload = {
+            "file": 1,
+            "mode": {"Frequency": {"cutoff": None}},
+            "samples": [
+                {
+                    "time": 0,
+                    "physical_time": 10.0,
+                    "state": {"data": [1.0, 1.0]},
+                    "mass": 1.0,
+                },
+                {
+                    "time": 40,
+                    "physical_time": 10.5,
+                    "state": {"data": [1.0, 1.0]},
+                    "mass": 1.0,
+                },
+            ],
+        }
+
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "1.json"
+            path.write_text(json.dumps(payload))
+
+            t, _ = load_json_samples(path)
+
+        np.testing.assert_array_equal(t, np.array([10.0, 10.5]))
+
 
 if __name__ == "__main__":
     unittest.main()
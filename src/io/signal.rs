@@ -3,8 +3,9 @@ Aggregate signal output.
 
 Purpose:
     `SignalWriter` persists compact aggregate state samples under
-    `{output_path}/signal/{n}.json`. The samples-per-chunk count is fixed at
-    construction from the configured byte budget and state length.
+    `{output_path}/signal/{n}.json`, or under another stream directory such
+    as `resources/` via `SignalWriter::named`. The samples-per-chunk count is
+    fixed at construction from the configured byte budget and state length.
 */
 
 use std::fs::{File, create_dir_all, read_to_string};
@@ -24,8 +25,14 @@ const ESTIMATED_FILE_OVERHEAD_BYTES: usize = 512;
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SignalRecord<T> {
     pub time: usize,
+    /// Physical time `t`; absent in output written before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_time: Option<f64>,
     pub state: Array1<T>,
     pub mass: T,
+    /// Index of the scheduled event applied just before this sample.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<usize>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -52,7 +59,18 @@ impl SignalWriter {
         max_bytes: usize,
         state_len: usize,
     ) -> Result<Self> {
-        let dir = output_path.join("signal");
+        Self::named(output_path, "signal", mode, max_bytes, state_len)
+    }
+
+    /// Writer for a secondary stream stored under `{output_path}/{stream}/`.
+    pub fn named(
+        output_path: &Path,
+        stream: &str,
+        mode: Mode<f64>,
+        max_bytes: usize,
+        state_len: usize,
+    ) -> Result<Self> {
+        let dir = output_path.join(stream);
         create_dir_all(&dir).map_err(|e| {
             Error::new(
                 e.kind(),
@@ -74,14 +92,38 @@ impl SignalWriter {
     }
 
     pub fn push(&mut self, gs: &SystemState<f64>) -> Result<()> {
+        self.push_record(gs, gs.physical_time, None)
+    }
+
+    /// Push `gs` as the sample at `physical_time`.
+    ///
+    /// Event-driven solvers hold the state constant between events and use
+    /// this to sample it on the save grid.
+    pub fn push_at(&mut self, gs: &SystemState<f64>, physical_time: f64) -> Result<()> {
+        self.push_record(gs, physical_time, None)
+    }
+
+    /// Push the post-event sample of scheduled event `index`.
+    pub fn push_event(&mut self, gs: &SystemState<f64>, index: usize) -> Result<()> {
+        self.push_record(gs, gs.physical_time, Some(index))
+    }
+
+    fn push_record(
+        &mut self,
+        gs: &SystemState<f64>,
+        physical_time: f64,
+        event: Option<usize>,
+    ) -> Result<()> {
         if self.samples.len() >= self.samples_per_chunk {
             self.flush()?;
         }
 
         self.samples.push(SignalRecord {
             time: gs.time,
+            physical_time: Some(physical_time),
             state: gs.state.clone(),
             mass: gs.mass,
+            event,
         });
         self.stats.samples += 1;
         Ok(())
@@ -26,6 +26,9 @@ const ESTIMATED_FILE_OVERHEAD_BYTES: usize = 512;
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SpaceRecord<T> {
     pub time: usize,
+    /// Physical time `t`; absent in output written before it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_time: Option<f64>,
     pub state: Array1<T>,
     pub space: ArrayD<T>,
     pub mass: T,
@@ -88,6 +91,7 @@ impl SpaceWriter {
 
         self.samples.push(SpaceRecord {
             time: gs.time,
+            physical_time: Some(gs.physical_time),
             state: gs.state.clone(),
             space: space.clone(),
             mass: gs.mass,
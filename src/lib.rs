@@ -6,8 +6,9 @@ Purpose:
     runners, and Cargo examples for ecological dynamical-system experiments.
 
 Current implementation boundary:
-    The ready solver path is replicator-form. GLV-named task modules are
-    placeholders until a dedicated GLV right-hand side and integrator are added.
+    Well-mixed solvers cover replicator and GLV population dynamics with
+    optional simplex or population noise; spatial solvers cover
+    local-replicator and GLV reaction-diffusion.
 */
 
 /// Target maximum JSON chunk size used by aggregate signal output writers.
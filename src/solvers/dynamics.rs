@@ -0,0 +1,604 @@
+/*!
+Per-cell reaction terms.
+
+Purpose:
+    `Dynamics` is the public extension point for local model right-hand sides.
+    The well-mixed solvers integrate `du/dt = reaction(u)` directly; the
+    spatial solver evaluates `reaction` once per cell and adds diffusion. Custom
+    models (Holling responses, saturating mutualism, and so on) implement the
+    trait and reuse the crate's integrators, sanitize logic, output writers,
+    and termination checks.
+
+Evolution contract:
+    `reaction` only evaluates the local vector field. Representation
+    invariants are enforced by the solver from `SystemState.mode` after every
+    raw step, not by the dynamics.
+*/
+
+use std::borrow::Cow;
+use std::io::{Error, ErrorKind, Result};
+
+use ndarray::{Array1, Array2};
+
+use crate::solvers::kernels::{add_scaled_row, affine_matvec_inplace, row_dot};
+
+/// Local reaction term `du/dt = f(u)` for one well-mixed community or cell.
+///
+/// Details:
+/// - Purpose: Evaluates a model's per-cell right-hand side over species
+///   abundances or frequencies.
+/// - Methods:
+///   - `reaction`: Writes `f(u)` into `out` (same length as `u`).
+///   - `jacobian`: Optionally writes the dense `∂f/∂u` into `out` and returns
+///     `true`; the default returns `false`, which makes implicit integrators
+///     reject the model.
+///   - `reaction_at` / `jacobian_at`: Time-dependent forms evaluated by the
+///     solvers at each stage time; they default to the autonomous methods.
+///   - `per_capita_at`: Writes the per-capita rate `f_i(u) / u_i` integrated
+///     by log-space solves. The default divides `reaction_at` by `u_i` and
+///     writes zero where `u_i = 0`; the built-in reactions override it to
+///     skip the division.
+pub trait Dynamics {
+    fn reaction(&self, u: &[f64], out: &mut [f64]);
+
+    fn jacobian(&self, _u: &[f64], _out: &mut Array2<f64>) -> bool {
+        false
+    }
+
+    fn reaction_at(&self, _t: f64, u: &[f64], out: &mut [f64]) {
+        self.reaction(u, out)
+    }
+
+    fn jacobian_at(&self, _t: f64, u: &[f64], out: &mut Array2<f64>) -> bool {
+        self.jacobian(u, out)
+    }
+
+    fn per_capita_at(&self, t: f64, u: &[f64], out: &mut [f64]) {
+        self.reaction_at(t, u, out);
+        for i in 0..u.len() {
+            out[i] = if u[i] > 0.0 { out[i] / u[i] } else { 0.0 };
+        }
+    }
+}
+
+/// Closures `|u, out| ...` can be used directly as dynamics.
+impl<F> Dynamics for F
+where
+    F: Fn(&[f64], &mut [f64]),
+{
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        self(u, out)
+    }
+}
+
+/// Replicator dynamics `ν_i (g_i + (Vν)_i - Υ)`.
+///
+/// Details:
+/// - Purpose: Built-in frequency dynamics with
+///   `Υ = Σ_i ν_i (g_i + (Vν)_i)` and an analytic Jacobian.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Growth vector `g`.
+#[derive(Clone, Copy)]
+pub struct Replicator<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+}
+
+/// Replicator–mutator dynamics `Σ_j Q_ji ν_j f_j - Υ ν_i`.
+///
+/// Details:
+/// - Purpose: Built-in quasispecies dynamics with fitness
+///   `f = g + Vν`, `Υ = Σ_i ν_i f_i`, and an analytic Jacobian. Offspring of
+///   type `j` mutate into type `i` with probability `Q_ji`; `Q = I` recovers
+///   `Replicator`.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Growth vector `g`.
+///   - `mutation_matrix`: Row-stochastic mutation matrix `Q`.
+#[derive(Clone, Copy)]
+pub struct ReplicatorMutator<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+    pub mutation_matrix: &'a Array2<f64>,
+}
+
+/// Immigration source `λ_i` added to another reaction term.
+///
+/// Details:
+/// - Purpose: Models an open community. Population states receive
+///   `f_i(u) + λ_i`; frequency states receive the centred source
+///   `f_i(u) + λ_i - ν_i Σ_j λ_j`, which keeps the simplex invariant.
+///   Species removed by the cutoff re-enter at rate `λ_i`.
+/// - Parameters:
+///   - `dynamics`: Wrapped reaction term.
+///   - `rates`: Per-species immigration rates `λ` (nonnegative).
+///   - `centred`: Whether to subtract `ν_i Σ_j λ_j` for frequency states.
+#[derive(Clone, Copy)]
+pub struct Immigration<'a, D> {
+    pub dynamics: D,
+    pub rates: &'a Array1<f64>,
+    pub centred: bool,
+}
+
+impl<'a, D: Dynamics> Immigration<'a, D> {
+    /// Uncentred source for `Mode::Population` states.
+    #[inline]
+    pub fn population(dynamics: D, rates: &'a Array1<f64>) -> Self {
+        Self {
+            dynamics,
+            rates,
+            centred: false,
+        }
+    }
+
+    /// Simplex-centred source for `Mode::Frequency` states.
+    #[inline]
+    pub fn frequency(dynamics: D, rates: &'a Array1<f64>) -> Self {
+        Self {
+            dynamics,
+            rates,
+            centred: true,
+        }
+    }
+}
+
+/// Generalized Lotka-Volterra population dynamics `n_i (g_i + (Vn)_i)`.
+///
+/// Details:
+/// - Purpose: Built-in population dynamics with an analytic Jacobian.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Growth vector `g`.
+#[derive(Clone, Copy)]
+pub struct GlvPopulation<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+}
+
+/// Borrow `g`, or allocate zeros of length `d` when it is omitted.
+#[inline]
+pub(crate) fn growth_vector_or_zeros(
+    growth_vector: Option<&Array1<f64>>,
+    d: usize,
+) -> Cow<'_, Array1<f64>> {
+    match growth_vector {
+        Some(g) => Cow::Borrowed(g),
+        None => Cow::Owned(Array1::zeros(d)),
+    }
+}
+
+/// Check that `V` is `d×d` and `g`, when given, has length `d`.
+pub(crate) fn validate_interactions(
+    interaction_dim: (usize, usize),     // shape of V
+    growth_vector: Option<&Array1<f64>>, // optional g
+    d: usize,                            // species count of the state
+) -> Result<()> {
+    if interaction_dim != (d, d) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "interaction_matrix must be square with size matching the state",
+        ));
+    }
+    if growth_vector.is_some_and(|g| g.len() != d) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "growth_vector length must match the state",
+        ));
+    }
+    Ok(())
+}
+
+/// Check that `Q` is a `d×d` row-stochastic matrix.
+pub(crate) fn validate_mutation_matrix(mutation_matrix: &Array2<f64>, d: usize) -> Result<()> {
+    if mutation_matrix.nrows() != d || mutation_matrix.ncols() != d {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "mutation_matrix must be square with size matching the state",
+        ));
+    }
+    if mutation_matrix.iter().any(|q| !q.is_finite() || *q < 0.0) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "mutation_matrix entries must be finite and nonnegative",
+        ));
+    }
+    if mutation_matrix
+        .rows()
+        .into_iter()
+        .any(|row| (row.sum() - 1.0).abs() > 1e-9)
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "mutation_matrix rows must sum to one",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check immigration rates against the species count and the step's cutoff.
+///
+/// A positive rate whose one-step inflow `λ_i dt` falls below the cutoff
+/// would be erased by `SystemState::sanitize` every step, so it is rejected.
+pub(crate) fn validate_immigration(
+    rates: &Array1<f64>,
+    d: usize,
+    cutoff: Option<f64>,
+    dt: f64,
+) -> Result<()> {
+    if rates.len() != d {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "immigration length must match the species axis",
+        ));
+    }
+    if rates.iter().any(|x| !x.is_finite() || *x < 0.0) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "immigration rates must be finite and nonnegative",
+        ));
+    }
+    let cutoff = cutoff.unwrap_or(0.0);
+    if rates.iter().any(|x| *x > 0.0 && x * dt < cutoff) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("immigration inflow rate * dt must reach the cutoff {cutoff}"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Write `g + V u` into `out` with the vectorised row kernel.
+#[inline]
+pub(crate) fn fitness_inplace(
+    u: &[f64],                        // state (len d)
+    growth_vector: &Array1<f64>,      // g (len d)
+    interaction_matrix: &Array2<f64>, // V (d×d)
+    out: &mut [f64],                  // output: g + Vu
+) {
+    affine_matvec_inplace(interaction_matrix, u, growth_vector, out);
+}
+
+impl Dynamics for Replicator<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        let d = u.len();
+
+        // out = g + Vν, then Υ = Σ_i ν_i out_i.
+        fitness_inplace(u, self.growth_vector, self.interaction_matrix, out);
+        let mut upsilon = 0.0;
+        for i in 0..d {
+            upsilon += u[i] * out[i];
+        }
+
+        // out = ν ⊙ (g + Vν - Υ)
+        for i in 0..d {
+            out[i] = u[i] * (out[i] - upsilon);
+        }
+    }
+
+    /// `g_i + (Vν)_i - Υ`.
+    fn per_capita_at(&self, _t: f64, u: &[f64], out: &mut [f64]) {
+        fitness_inplace(u, self.growth_vector, self.interaction_matrix, out);
+        let upsilon: f64 = u.iter().zip(out.iter()).map(|(x, f)| x * f).sum();
+        for f in out.iter_mut() {
+            *f -= upsilon;
+        }
+    }
+
+    /// `J_ij = δ_ij (g_i + (Vν)_i - Υ) + ν_i (V_ij - ∂Υ/∂ν_j)`, where
+    /// `∂Υ/∂ν_j = g_j + (Vν)_j + Σ_k ν_k V_kj`.
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = u.len();
+        let v = self.interaction_matrix;
+
+        let mut fitness = vec![0.0; d];
+        fitness_inplace(u, self.growth_vector, v, &mut fitness);
+        let mut upsilon = 0.0;
+        for i in 0..d {
+            upsilon += u[i] * fitness[i];
+        }
+
+        // col_j = ∂Υ/∂ν_j
+        let mut col = vec![0.0; d];
+        for j in 0..d {
+            let mut acc = 0.0;
+            for k in 0..d {
+                acc += u[k] * v[(k, j)];
+            }
+            col[j] = fitness[j] + acc;
+        }
+
+        for i in 0..d {
+            for j in 0..d {
+                out[(i, j)] = u[i] * (v[(i, j)] - col[j]);
+            }
+            out[(i, i)] += fitness[i] - upsilon;
+        }
+
+        true
+    }
+}
+
+impl Dynamics for ReplicatorMutator<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        // One pass over rows j: w_j = ν_j (g_j + (Vν)_j), Υ = Σ_j w_j, and
+        // out = Qᵀw accumulated from the contiguous rows Q_j·.
+        out.fill(0.0);
+        let mut upsilon = 0.0;
+        for j in 0..u.len() {
+            let w = u[j] * (self.growth_vector[j] + row_dot(self.interaction_matrix, j, u));
+            upsilon += w;
+            add_scaled_row(self.mutation_matrix, j, w, out);
+        }
+
+        // out_i = Σ_j Q_ji w_j - Υ ν_i.
+        for (o, x) in out.iter_mut().zip(u) {
+            *o -= upsilon * x;
+        }
+    }
+
+    /// `J_ik = Q_ki f_k + Σ_j Q_ji ν_j V_jk - δ_ik Υ - ν_i ∂Υ/∂ν_k`, where
+    /// `∂Υ/∂ν_k = f_k + Σ_j ν_j V_jk`.
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = u.len();
+        let v = self.interaction_matrix;
+        let q = self.mutation_matrix;
+
+        let mut fitness = vec![0.0; d];
+        fitness_inplace(u, self.growth_vector, v, &mut fitness);
+        let mut upsilon = 0.0;
+        for i in 0..d {
+            upsilon += u[i] * fitness[i];
+        }
+
+        // col_k = ∂Υ/∂ν_k
+        let mut col = vec![0.0; d];
+        for k in 0..d {
+            let mut acc = 0.0;
+            for j in 0..d {
+                acc += u[j] * v[(j, k)];
+            }
+            col[k] = fitness[k] + acc;
+        }
+
+        for i in 0..d {
+            for k in 0..d {
+                let mut acc = q[(k, i)] * fitness[k];
+                for j in 0..d {
+                    acc += q[(j, i)] * u[j] * v[(j, k)];
+                }
+                out[(i, k)] = acc - u[i] * col[k];
+            }
+            out[(i, i)] -= upsilon;
+        }
+
+        true
+    }
+}
+
+impl<D: Dynamics> Dynamics for Immigration<'_, D> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        self.reaction_at(0.0, u, out);
+    }
+
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        self.jacobian_at(0.0, u, out)
+    }
+
+    #[inline]
+    fn reaction_at(&self, t: f64, u: &[f64], out: &mut [f64]) {
+        self.dynamics.reaction_at(t, u, out);
+
+        let total = if self.centred { self.rates.sum() } else { 0.0 };
+        for i in 0..u.len() {
+            out[i] += self.rates[i] - total * u[i];
+        }
+    }
+
+    /// Wrapped rate plus `λ_i / u_i - Σ_j λ_j` (centred); `+∞` where
+    /// `u_i = 0 < λ_i`, since the source has no per-capita form there.
+    fn per_capita_at(&self, t: f64, u: &[f64], out: &mut [f64]) {
+        self.dynamics.per_capita_at(t, u, out);
+
+        let total = if self.centred { self.rates.sum() } else { 0.0 };
+        for i in 0..u.len() {
+            if self.rates[i] > 0.0 {
+                out[i] += self.rates[i] / u[i];
+            }
+            out[i] -= total;
+        }
+    }
+
+    /// Wrapped Jacobian, minus `Σ_j λ_j` on the diagonal when centred.
+    fn jacobian_at(&self, t: f64, u: &[f64], out: &mut Array2<f64>) -> bool {
+        if !self.dynamics.jacobian_at(t, u, out) {
+            return false;
+        }
+        if self.centred {
+            let total = self.rates.sum();
+            for i in 0..u.len() {
+                out[(i, i)] -= total;
+            }
+        }
+
+        true
+    }
+}
+
+impl Dynamics for GlvPopulation<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        // out = n ⊙ (g + Vn)
+        fitness_inplace(u, self.growth_vector, self.interaction_matrix, out);
+        for i in 0..u.len() {
+            out[i] *= u[i];
+        }
+    }
+
+    /// `g_i + (Vn)_i`.
+    fn per_capita_at(&self, _t: f64, u: &[f64], out: &mut [f64]) {
+        fitness_inplace(u, self.growth_vector, self.interaction_matrix, out);
+    }
+
+    /// `J_ij = δ_ij (g_i + (Vn)_i) + n_i V_ij`.
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = u.len();
+        let v = self.interaction_matrix;
+
+        let mut fitness = vec![0.0; d];
+        fitness_inplace(u, self.growth_vector, v, &mut fitness);
+
+        for i in 0..d {
+            for j in 0..d {
+                out[(i, j)] = u[i] * v[(i, j)];
+            }
+            out[(i, i)] += fitness[i];
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn assert_jacobian_matches_finite_differences(dynamics: &dyn Dynamics, u: [f64; 3]) {
+        let mut jac = Array2::zeros((3, 3));
+        assert!(dynamics.jacobian(&u, &mut jac));
+
+        let eps = 1e-7;
+        let mut f_plus = [0.0; 3];
+        let mut f_minus = [0.0; 3];
+        for j in 0..3 {
+            let mut plus = u;
+            let mut minus = u;
+            plus[j] += eps;
+            minus[j] -= eps;
+            dynamics.reaction(&plus, &mut f_plus);
+            dynamics.reaction(&minus, &mut f_minus);
+            for i in 0..3 {
+                let fd = (f_plus[i] - f_minus[i]) / (2.0 * eps);
+                assert!((jac[(i, j)] - fd).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_jacobians_match_finite_differences() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+
+        assert_jacobian_matches_finite_differences(
+            &Replicator {
+                interaction_matrix: &v,
+                growth_vector: &g,
+            },
+            [0.2, 0.3, 0.5],
+        );
+        assert_jacobian_matches_finite_differences(
+            &GlvPopulation {
+                interaction_matrix: &v,
+                growth_vector: &g,
+            },
+            [1.5, 0.3, 2.0],
+        );
+        let q = array![[0.9, 0.05, 0.05], [0.1, 0.8, 0.1], [0.0, 0.3, 0.7]];
+        assert_jacobian_matches_finite_differences(
+            &ReplicatorMutator {
+                interaction_matrix: &v,
+                growth_vector: &g,
+                mutation_matrix: &q,
+            },
+            [0.2, 0.3, 0.5],
+        );
+    }
+
+    #[test]
+    fn replicator_mutator_stays_on_the_simplex_and_reduces_to_replicator() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+        let q = array![[0.9, 0.05, 0.05], [0.1, 0.8, 0.1], [0.0, 0.3, 0.7]];
+        let identity = Array2::eye(3);
+        let u = [0.2, 0.3, 0.5];
+
+        let mut mutator = [0.0; 3];
+        ReplicatorMutator {
+            interaction_matrix: &v,
+            growth_vector: &g,
+            mutation_matrix: &q,
+        }
+        .reaction(&u, &mut mutator);
+        assert!(mutator.iter().sum::<f64>().abs() < 1e-12);
+
+        let mut pure = [0.0; 3];
+        let mut unmutated = [0.0; 3];
+        Replicator {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        }
+        .reaction(&u, &mut pure);
+        ReplicatorMutator {
+            interaction_matrix: &v,
+            growth_vector: &g,
+            mutation_matrix: &identity,
+        }
+        .reaction(&u, &mut unmutated);
+        for i in 0..3 {
+            assert!((pure[i] - unmutated[i]).abs() < 1e-12);
+        }
+
+        assert!(validate_mutation_matrix(&q, 3).is_ok());
+        assert!(validate_mutation_matrix(&array![[0.5, 0.4], [0.0, 1.0]], 2).is_err());
+        assert!(validate_mutation_matrix(&q, 2).is_err());
+    }
+
+    #[test]
+    fn immigration_adds_a_source_and_keeps_the_simplex_when_centred() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+        let rates = array![0.01, 0.0, 0.03];
+        let replicator = Replicator {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        };
+        let glv = GlvPopulation {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        };
+
+        let mut out = [0.0; 3];
+        Immigration::frequency(replicator, &rates).reaction(&[0.2, 0.3, 0.5], &mut out);
+        assert!(out.iter().sum::<f64>().abs() < 1e-12);
+        Immigration::population(glv, &rates).reaction(&[0.0, 0.0, 0.0], &mut out);
+        assert_eq!(out, [0.01, 0.0, 0.03]);
+
+        assert_jacobian_matches_finite_differences(
+            &Immigration::frequency(replicator, &rates),
+            [0.2, 0.3, 0.5],
+        );
+        assert!(validate_immigration(&rates, 3, Some(1e-3), 0.1).is_ok());
+        assert!(validate_immigration(&rates, 3, Some(1e-2), 0.1).is_err());
+        assert!(validate_immigration(&array![-0.1, 0.0, 0.0], 3, None, 0.1).is_err());
+    }
+
+    #[test]
+    fn closures_are_dynamics_without_jacobian() {
+        let logistic = |u: &[f64], out: &mut [f64]| {
+            for (o, x) in out.iter_mut().zip(u) {
+                *o = x * (1.0 - x);
+            }
+        };
+        let mut out = [0.0; 2];
+        logistic.reaction(&[0.5, 2.0], &mut out);
+        assert_eq!(out, [0.25, -2.0]);
+        assert!(!logistic.jacobian(&[0.5, 2.0], &mut Array2::zeros((2, 2))));
+    }
+}
@@ -0,0 +1,355 @@
+/*!
+Scheduled species introductions.
+
+Purpose:
+    An `EventSchedule` introduces invaders into a running community. Events
+    fire strictly in schedule order: each one is armed after the previous one
+    fired, and fires either at a fixed step or when a steady-state check on
+    the post-arming trajectory succeeds. An introduction adds density to
+    chosen species, globally or in chosen spatial cells.
+
+Event contract:
+    Events are applied after the step's sanitize and regular snapshot. The
+    solver then re-sanitizes, pushes an extra signal sample marked with the
+    event index, and records an `EventRecord` in `SolveOutcome.events`. While
+    events are pending, the solver's own termination checks are skipped, so a
+    run ends early only after the schedule is exhausted.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solvers::termination::{TerminationChecker, TerminationConfig, TerminationReason};
+use crate::{Mode, SystemState};
+
+/// When an event fires, relative to the step at which it was armed.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum EventTrigger {
+    /// Fire at the first step `>= step`.
+    Step(usize),
+
+    /// Fire when a fresh checker with this configuration reports a reason.
+    /// History starts empty when the event is armed, and `min_steps` counts
+    /// from that point.
+    SteadyState(TerminationConfig),
+}
+
+/// Density added to one species by an event.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Introduction {
+    pub species: usize,
+    pub density: f64,
+}
+
+/// One scheduled introduction.
+///
+/// Details:
+/// - Purpose: Population states receive `x_i += density`. Frequency states
+///   are mixed as `ν ← (1 - Σδ) ν + δ`, so an absent invader enters at
+///   exactly `δ` and the simplex is kept.
+/// - Parameters:
+///   - `trigger`: When the event fires.
+///   - `introductions`: Species and densities to add.
+///   - `cells`: Spatial cell indices, excluding the species axis; `None`
+///     introduces into every cell (or the global state when well-mixed).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledEvent {
+    pub trigger: EventTrigger,
+    pub introductions: Vec<Introduction>,
+    pub cells: Option<Vec<Vec<usize>>>,
+}
+
+impl ScheduledEvent {
+    #[inline]
+    pub fn at_step(step: usize) -> Self {
+        Self {
+            trigger: EventTrigger::Step(step),
+            introductions: Vec::new(),
+            cells: None,
+        }
+    }
+
+    #[inline]
+    pub fn at_steady_state(config: TerminationConfig) -> Self {
+        Self {
+            trigger: EventTrigger::SteadyState(config),
+            introductions: Vec::new(),
+            cells: None,
+        }
+    }
+
+    /// Add `density` of `species` when the event fires.
+    pub fn introduce(mut self, species: usize, density: f64) -> Self {
+        self.introductions.push(Introduction { species, density });
+        self
+    }
+
+    /// Restrict the introduction to the given spatial cells.
+    pub fn in_cells(mut self, cells: &[Vec<usize>]) -> Self {
+        self.cells = Some(cells.to_vec());
+        self
+    }
+}
+
+/// Ordered list of scheduled introductions.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EventSchedule {
+    pub events: Vec<ScheduledEvent>,
+}
+
+impl EventSchedule {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an event; it is armed once every earlier event has fired.
+    pub fn event(mut self, event: ScheduledEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// What fired an applied event.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum EventCause {
+    Step,
+    SteadyState(TerminationReason),
+}
+
+/// An applied event, as recorded in `SolveOutcome` and task metadata.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventRecord {
+    pub index: usize,
+    pub step: usize,
+    pub time: usize,
+    #[serde(default)]
+    pub physical_time: f64,
+    pub cause: EventCause,
+    pub introductions: Vec<Introduction>,
+    pub cells: Option<Vec<Vec<usize>>>,
+}
+
+/// Solver-side cursor over an `EventSchedule`.
+pub(crate) struct EventQueue<'a> {
+    events: &'a [ScheduledEvent],
+    next: usize,
+    armed_step: usize,
+    checker: Option<TerminationChecker>,
+}
+
+impl<'a> EventQueue<'a> {
+    /// Validate `schedule` against the state shape and arm its first event.
+    ///
+    /// `spatial_shape` excludes the species axis and is `None` for well-mixed
+    /// solvers, which reject cell placements.
+    pub(crate) fn new(
+        schedule: &'a EventSchedule,
+        gs: &SystemState<f64>,
+        num_species: usize,
+        spatial_shape: Option<&[usize]>,
+    ) -> Result<Self> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidInput, message);
+
+        for (k, event) in schedule.events.iter().enumerate() {
+            if let EventTrigger::SteadyState(config) = event.trigger
+                && config.is_disabled()
+            {
+                return Err(invalid(format!(
+                    "event {k}: steady-state trigger needs an enabled check"
+                )));
+            }
+            for intro in &event.introductions {
+                if intro.species >= num_species {
+                    return Err(invalid(format!(
+                        "event {k}: species {} is outside the species axis",
+                        intro.species
+                    )));
+                }
+                if !intro.density.is_finite() || intro.density < 0.0 {
+                    return Err(invalid(format!(
+                        "event {k}: density must be finite and nonnegative"
+                    )));
+                }
+            }
+            if matches!(gs.mode, Mode::Frequency { .. }) {
+                let total: f64 = event.introductions.iter().map(|i| i.density).sum();
+                if total > 1.0 {
+                    return Err(invalid(format!(
+                        "event {k}: frequency introductions must sum to <= 1"
+                    )));
+                }
+            }
+            match (&event.cells, spatial_shape) {
+                (None, _) => {}
+                (Some(_), None) => {
+                    return Err(invalid(format!(
+                        "event {k}: cell placement requires a spatial solver"
+                    )));
+                }
+                (Some(cells), Some(shape)) => {
+                    let in_bounds = |cell: &Vec<usize>| {
+                        cell.len() == shape.len() && cell.iter().zip(shape).all(|(i, n)| i < n)
+                    };
+                    if !cells.iter().all(in_bounds) {
+                        return Err(invalid(format!("event {k}: cell index out of bounds")));
+                    }
+                }
+            }
+        }
+
+        let mut queue = Self {
+            events: &schedule.events,
+            next: 0,
+            armed_step: 0,
+            checker: None,
+        };
+        queue.arm(0)?;
+        Ok(queue)
+    }
+
+    #[inline]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.next < self.events.len()
+    }
+
+    /// Return the next event if its trigger fires on `gs` at `step`.
+    pub(crate) fn poll(
+        &mut self,
+        gs: &SystemState<f64>,
+        step: usize,
+    ) -> Result<Option<(usize, &'a ScheduledEvent, EventCause)>> {
+        let Some(event) = self.events.get(self.next) else {
+            return Ok(None);
+        };
+
+        let cause = match event.trigger {
+            EventTrigger::Step(at) => (step >= at).then_some(EventCause::Step),
+            EventTrigger::SteadyState(_) => self
+                .checker
+                .as_mut()
+                .and_then(|checker| checker.check(gs, step - self.armed_step))
+                .map(EventCause::SteadyState),
+        };
+        let Some(cause) = cause else {
+            return Ok(None);
+        };
+
+        let index = self.next;
+        self.next += 1;
+        self.arm(step)?;
+        Ok(Some((index, event, cause)))
+    }
+
+    fn arm(&mut self, step: usize) -> Result<()> {
+        self.armed_step = step;
+        self.checker = match self.events.get(self.next).map(|event| event.trigger) {
+            Some(EventTrigger::SteadyState(config)) => TerminationChecker::new(config)?,
+            _ => None,
+        };
+        Ok(())
+    }
+}
+
+/// Add an event's introductions to one species-contiguous block.
+///
+/// Details:
+/// - Purpose: Applies the population or frequency rule of `ScheduledEvent`
+///   to one well-mixed state or one spatial cell; the caller re-sanitizes.
+pub(crate) fn introduce_inplace(u: &mut [f64], event: &ScheduledEvent, frequency: bool) {
+    if frequency {
+        let total: f64 = event.introductions.iter().map(|i| i.density).sum();
+        for x in u.iter_mut() {
+            *x *= 1.0 - total;
+        }
+    }
+    for intro in &event.introductions {
+        u[intro.species] += intro.density;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::termination::{
+        AdaptiveFixedPointConfig, SteadyStateConfig, TerminationObservable,
+    };
+    use ndarray::array;
+
+    #[test]
+    fn events_fire_in_order_and_steady_state_triggers_rearm() {
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![1.0, 0.0],
+            None,
+        );
+        let steady = TerminationConfig {
+            monoculture: false,
+            survivor_tolerance: None,
+            steady_state: SteadyStateConfig::Adaptive {
+                fixed_point: AdaptiveFixedPointConfig {
+                    tolerance: 1e-9,
+                    min_steps: 3,
+                    min_window: 2,
+                    max_window: 2,
+                    stable_checks_required: 1,
+                },
+                oscillation: None,
+            },
+            observable: TerminationObservable::GlobalState,
+            check_interval: 1,
+        };
+        let schedule = EventSchedule::new()
+            .event(ScheduledEvent::at_step(2).introduce(1, 0.5))
+            .event(ScheduledEvent::at_steady_state(steady).introduce(1, 0.25));
+        let mut queue = EventQueue::new(&schedule, &gs, 2, None).expect("valid schedule");
+
+        assert!(queue.poll(&gs, 1).unwrap().is_none());
+        let (index, _, cause) = queue.poll(&gs, 2).unwrap().expect("step event fires");
+        assert_eq!((index, cause), (0, EventCause::Step));
+
+        // min_steps counts from arming at step 2.
+        assert!(queue.poll(&gs, 3).unwrap().is_none());
+        assert!(queue.poll(&gs, 4).unwrap().is_none());
+        let (index, event, cause) = queue.poll(&gs, 5).unwrap().expect("steady state fires");
+        assert_eq!(index, 1);
+        assert!(matches!(cause, EventCause::SteadyState(_)));
+        assert!(!queue.is_pending());
+
+        let mut u = [0.5, 0.5];
+        introduce_inplace(&mut u, event, true);
+        assert_eq!(u, [0.375, 0.625]);
+    }
+
+    #[test]
+    fn schedules_are_validated_against_the_state() {
+        let gs = SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![1.0], None);
+        let check = |event: ScheduledEvent, shape: Option<&[usize]>| {
+            EventQueue::new(&EventSchedule::new().event(event), &gs, 2, shape).is_ok()
+        };
+
+        assert!(check(ScheduledEvent::at_step(1).introduce(1, 0.1), None));
+        assert!(!check(ScheduledEvent::at_step(1).introduce(2, 0.1), None));
+        assert!(!check(ScheduledEvent::at_step(1).introduce(1, 1.5), None));
+        assert!(!check(
+            ScheduledEvent::at_steady_state(TerminationConfig::disabled()),
+            None
+        ));
+        let cell = ScheduledEvent::at_step(1)
+            .introduce(0, 0.1)
+            .in_cells(&[vec![2]]);
+        assert!(!check(cell.clone(), None));
+        assert!(check(cell.clone(), Some(&[3])));
+        assert!(!check(cell, Some(&[2])));
+    }
+}
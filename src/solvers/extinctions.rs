@@ -0,0 +1,111 @@
+/*!
+Extinction log.
+
+Purpose:
+    Species removed by the cutoff, a nonnegativity clamp, or a discrete
+    death only show up in the signal stream at save resolution. Every solver
+    instead compares the global state before and after each step and records
+    an `ExtinctionRecord` in `SolveOutcome.extinctions` whenever a species
+    drops from a positive abundance to exactly zero.
+
+Record contract:
+    `step` and `time` are the solver step and physical time at which the
+    species first reads zero. `abundance` is its value in the state before
+    that step (or before the event that removed it). A species that is
+    reintroduced and dies out again is recorded again.
+*/
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+/// One species dropping to zero.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExtinctionRecord {
+    pub step: usize,
+    pub time: f64,
+    pub species: usize,
+    pub abundance: f64,
+}
+
+/// Extinction counts of a run, as recorded in task metadata.
+///
+/// Details:
+/// - Parameters:
+///   - `extinctions`: Number of records.
+///   - `extinct_species`: Number of distinct species with a record.
+///   - `first_time` / `last_time`: Physical time of the first and last
+///     record, if any.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ExtinctionSummary {
+    pub extinctions: usize,
+    pub extinct_species: usize,
+    pub first_time: Option<f64>,
+    pub last_time: Option<f64>,
+}
+
+impl ExtinctionSummary {
+    pub fn from_records(records: &[ExtinctionRecord]) -> Self {
+        let species: BTreeSet<usize> = records.iter().map(|r| r.species).collect();
+        Self {
+            extinctions: records.len(),
+            extinct_species: species.len(),
+            first_time: records.first().map(|r| r.time),
+            last_time: records.last().map(|r| r.time),
+        }
+    }
+}
+
+/// Append a record for every species with `previous_i > 0` and `current_i == 0`.
+#[inline]
+pub(crate) fn record_extinctions(
+    previous: &[f64],                // state before the update
+    current: &[f64],                 // state after the update
+    step: usize,                     // solver step of `current`
+    time: f64,                       // physical time of `current`
+    log: &mut Vec<ExtinctionRecord>, // output log
+) {
+    for (species, (&before, &after)) in previous.iter().zip(current).enumerate() {
+        if before > 0.0 && after == 0.0 {
+            log.push(ExtinctionRecord {
+                step,
+                time,
+                species,
+                abundance: before,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_positive_to_zero_transitions_are_recorded_and_summarized() {
+        let mut log = Vec::new();
+        record_extinctions(&[0.5, 0.0, 2.0], &[0.0, 0.0, 1.0], 3, 0.3, &mut log);
+        record_extinctions(&[0.0, 0.1, 1.0], &[0.2, 0.1, 0.0], 7, 0.7, &mut log);
+        record_extinctions(&[0.2, 0.1, 0.0], &[0.0, 0.1, 0.0], 9, 0.9, &mut log);
+
+        assert_eq!(
+            log.iter()
+                .map(|r| (r.step, r.species, r.abundance))
+                .collect::<Vec<_>>(),
+            vec![(3, 0, 0.5), (7, 2, 1.0), (9, 0, 0.2)]
+        );
+        assert_eq!(
+            ExtinctionSummary::from_records(&log),
+            ExtinctionSummary {
+                extinctions: 3,
+                extinct_species: 2,
+                first_time: Some(0.3),
+                last_time: Some(0.9),
+            }
+        );
+        assert_eq!(
+            ExtinctionSummary::from_records(&[]),
+            ExtinctionSummary::default()
+        );
+    }
+}
@@ -0,0 +1,399 @@
+/*!
+Environmental forcing of growth vectors and interaction matrices.
+
+Purpose:
+    `Forcing` is a serializable description of how `g` and `V` change with
+    `SystemState::physical_time`: a seasonal sinusoid on the growth vector,
+    or piecewise-constant regimes that replace `g` and/or `V` from given
+    start times. `Forced` applies a forcing to the built-in replicator or GLV
+    reaction through `Dynamics::reaction_at`, so every integrator stage sees
+    its own stage time. Arbitrary time dependence is available by wrapping a
+    closure `|t, u, out|` in `TimeDependent`.
+
+Recording contract:
+    Tasks write the `Forcing` value into `metadata.json` unchanged, so the
+    exact forcing can be rebuilt from a run's output directory.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use crate::solvers::dynamics::{Dynamics, GlvPopulation, Replicator};
+
+/// One piecewise-constant regime; `None` keeps the base parameter.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Regime {
+    pub start: f64,
+    pub growth_vector: Option<Array1<f64>>,
+    pub interaction_matrix: Option<Array2<f64>>,
+}
+
+/// Time dependence of `g` and `V`.
+///
+/// Details:
+/// - Variants:
+///   - `Seasonal`: `g(t) = g0 + amplitude * sin(omega t + phase)`; `V` is
+///     constant.
+///   - `Piecewise`: Regime `k` applies on `[start_k, start_{k+1})`; before
+///     the first start the base `g0` and `V0` apply.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Forcing {
+    Seasonal {
+        amplitude: Array1<f64>,
+        omega: f64,
+        phase: f64,
+    },
+    Piecewise {
+        regimes: Vec<Regime>,
+    },
+}
+
+impl Forcing {
+    #[inline]
+    pub fn seasonal(amplitude: Array1<f64>, omega: f64, phase: f64) -> Self {
+        Self::Seasonal {
+            amplitude,
+            omega,
+            phase,
+        }
+    }
+
+    /// Empty piecewise forcing; add regimes with `regime`.
+    #[inline]
+    pub fn piecewise() -> Self {
+        Self::Piecewise {
+            regimes: Vec::new(),
+        }
+    }
+
+    /// Append a regime starting at `start`. No-op for `Seasonal`.
+    pub fn regime(
+        mut self,
+        start: f64,
+        growth_vector: Option<Array1<f64>>,
+        interaction_matrix: Option<Array2<f64>>,
+    ) -> Self {
+        if let Self::Piecewise { regimes } = &mut self {
+            regimes.push(Regime {
+                start,
+                growth_vector,
+                interaction_matrix,
+            });
+        }
+        self
+    }
+
+    /// Effective `(g, V)` at time `t`.
+    pub fn parameters_at<'a>(
+        &'a self,
+        t: f64,
+        growth_vector: &'a Array1<f64>,
+        interaction_matrix: &'a Array2<f64>,
+    ) -> (Array1<f64>, &'a Array2<f64>) {
+        match self {
+            Self::Seasonal {
+                amplitude,
+                omega,
+                phase,
+            } => (
+                growth_vector + &(amplitude * (omega * t + phase).sin()),
+                interaction_matrix,
+            ),
+            Self::Piecewise { regimes } => {
+                let (g, v) = regime_parameters(regimes, t, growth_vector, interaction_matrix);
+                (g.clone(), v)
+            }
+        }
+    }
+
+    /// Check shapes against `d` species and that regime starts increase.
+    pub(crate) fn validate(&self, d: usize) -> Result<()> {
+        let invalid = |message: &str| Error::new(ErrorKind::InvalidInput, message.to_owned());
+
+        match self {
+            Self::Seasonal {
+                amplitude,
+                omega,
+                phase,
+            } => {
+                if amplitude.len() != d {
+                    return Err(invalid("forcing amplitude length must match species"));
+                }
+                if amplitude.iter().any(|x| !x.is_finite())
+                    || !omega.is_finite()
+                    || !phase.is_finite()
+                {
+                    return Err(invalid("seasonal forcing must be finite"));
+                }
+            }
+            Self::Piecewise { regimes } => {
+                for (k, regime) in regimes.iter().enumerate() {
+                    if !regime.start.is_finite() {
+                        return Err(invalid("regime start must be finite"));
+                    }
+                    if k > 0 && regime.start <= regimes[k - 1].start {
+                        return Err(invalid("regime starts must strictly increase"));
+                    }
+                    if regime.growth_vector.as_ref().is_some_and(|g| g.len() != d) {
+                        return Err(invalid("regime growth_vector length must match species"));
+                    }
+                    if regime
+                        .interaction_matrix
+                        .as_ref()
+                        .is_some_and(|v| v.dim() != (d, d))
+                    {
+                        return Err(invalid("regime interaction_matrix must be d×d"));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters of the regime active at `t`, falling back to the base ones.
+#[inline]
+fn regime_parameters<'a>(
+    regimes: &'a [Regime],
+    t: f64,
+    growth_vector: &'a Array1<f64>,
+    interaction_matrix: &'a Array2<f64>,
+) -> (&'a Array1<f64>, &'a Array2<f64>) {
+    let active = regimes.partition_point(|regime| regime.start <= t);
+    match active.checked_sub(1).map(|k| &regimes[k]) {
+        None => (growth_vector, interaction_matrix),
+        Some(regime) => (
+            regime.growth_vector.as_ref().unwrap_or(growth_vector),
+            regime
+                .interaction_matrix
+                .as_ref()
+                .unwrap_or(interaction_matrix),
+        ),
+    }
+}
+
+/// Built-in replicator or GLV reaction under a `Forcing`.
+///
+/// Details:
+/// - Purpose: Evaluates `Replicator` (`frequency`) or `GlvPopulation` with
+///   the forced `g(t)` and `V(t)`. Piecewise regimes borrow their parameters;
+///   seasonal forcing adds `s a_i` to the fitness with `s = sin(ωt + φ)`,
+///   so the reaction does not allocate. `reaction` evaluates at `t = 0`.
+/// - Parameters:
+///   - `interaction_matrix`: Base interaction matrix `V0`.
+///   - `growth_vector`: Base growth vector `g0`.
+///   - `forcing`: Time dependence of `g` and `V`.
+///   - `frequency`: Replicator (`true`) or GLV (`false`) reaction.
+#[derive(Clone, Copy)]
+pub struct Forced<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+    pub forcing: &'a Forcing,
+    pub frequency: bool,
+}
+
+impl<'a> Forced<'a> {
+    #[inline]
+    pub fn replicator(
+        interaction_matrix: &'a Array2<f64>,
+        growth_vector: &'a Array1<f64>,
+        forcing: &'a Forcing,
+    ) -> Self {
+        Self {
+            interaction_matrix,
+            growth_vector,
+            forcing,
+            frequency: true,
+        }
+    }
+
+    #[inline]
+    pub fn glv(
+        interaction_matrix: &'a Array2<f64>,
+        growth_vector: &'a Array1<f64>,
+        forcing: &'a Forcing,
+    ) -> Self {
+        Self {
+            interaction_matrix,
+            growth_vector,
+            forcing,
+            frequency: false,
+        }
+    }
+
+    fn base(
+        &self,
+        growth_vector: &'a Array1<f64>,
+        interaction_matrix: &'a Array2<f64>,
+    ) -> Base<'a> {
+        if self.frequency {
+            Base::Replicator(Replicator {
+                interaction_matrix,
+                growth_vector,
+            })
+        } else {
+            Base::Glv(GlvPopulation {
+                interaction_matrix,
+                growth_vector,
+            })
+        }
+    }
+}
+
+enum Base<'a> {
+    Replicator(Replicator<'a>),
+    Glv(GlvPopulation<'a>),
+}
+
+impl Base<'_> {
+    fn as_dynamics(&self) -> &dyn Dynamics {
+        match self {
+            Base::Replicator(replicator) => replicator,
+            Base::Glv(glv) => glv,
+        }
+    }
+}
+
+impl Dynamics for Forced<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        self.reaction_at(0.0, u, out);
+    }
+
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        self.jacobian_at(0.0, u, out)
+    }
+
+    fn reaction_at(&self, t: f64, u: &[f64], out: &mut [f64]) {
+        match self.forcing {
+            Forcing::Piecewise { regimes } => {
+                let (g, v) =
+                    regime_parameters(regimes, t, self.growth_vector, self.interaction_matrix);
+                self.base(g, v).as_dynamics().reaction(u, out);
+            }
+            Forcing::Seasonal {
+                amplitude,
+                omega,
+                phase,
+            } => {
+                self.base(self.growth_vector, self.interaction_matrix)
+                    .as_dynamics()
+                    .reaction(u, out);
+
+                // Fitness shift s a_i; replicator also shifts Υ by s Σ_k u_k a_k.
+                let s = (omega * t + phase).sin();
+                let mean = if self.frequency {
+                    u.iter().zip(amplitude).map(|(x, a)| x * a).sum()
+                } else {
+                    0.0
+                };
+                for i in 0..u.len() {
+                    out[i] += s * u[i] * (amplitude[i] - mean);
+                }
+            }
+        }
+    }
+
+    fn jacobian_at(&self, t: f64, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let (g, v) = self
+            .forcing
+            .parameters_at(t, self.growth_vector, self.interaction_matrix);
+        self.base(&g, v).as_dynamics().jacobian(u, out)
+    }
+}
+
+/// Closure `|t, u, out| ...` used as time-dependent dynamics.
+///
+/// `reaction` evaluates the closure at `t = 0`; solvers call `reaction_at`.
+#[derive(Clone, Copy)]
+pub struct TimeDependent<F>(pub F);
+
+impl<F> Dynamics for TimeDependent<F>
+where
+    F: Fn(f64, &[f64], &mut [f64]),
+{
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        (self.0)(0.0, u, out)
+    }
+
+    #[inline]
+    fn reaction_at(&self, t: f64, u: &[f64], out: &mut [f64]) {
+        (self.0)(t, u, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn seasonal_reaction_and_jacobian_match_the_shifted_growth_vector() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+        let forcing = Forcing::seasonal(array![0.5, -0.3, 0.2], 2.0, 0.3);
+        let u = [0.2, 0.3, 0.5];
+        let t = 0.7;
+        let (g_t, _) = forcing.parameters_at(t, &g, &v);
+
+        for frequency in [true, false] {
+            let forced = Forced {
+                interaction_matrix: &v,
+                growth_vector: &g,
+                forcing: &forcing,
+                frequency,
+            };
+            let shifted = Forced {
+                interaction_matrix: &v,
+                growth_vector: &g_t,
+                forcing: &Forcing::piecewise(),
+                frequency,
+            };
+
+            let mut expected = [0.0; 3];
+            let mut actual = [0.0; 3];
+            shifted.reaction(&u, &mut expected);
+            forced.reaction_at(t, &u, &mut actual);
+            for i in 0..3 {
+                assert!((expected[i] - actual[i]).abs() < 1e-14);
+            }
+
+            let mut expected = Array2::zeros((3, 3));
+            let mut actual = Array2::zeros((3, 3));
+            assert!(shifted.jacobian(&u, &mut expected));
+            assert!(forced.jacobian_at(t, &u, &mut actual));
+            assert!((expected - actual).iter().all(|x| x.abs() < 1e-14));
+        }
+    }
+
+    #[test]
+    fn piecewise_regimes_switch_at_their_start_times() {
+        let g = array![1.0, 1.0];
+        let v = Array2::zeros((2, 2));
+        let forcing = Forcing::piecewise()
+            .regime(1.0, Some(array![2.0, 0.0]), None)
+            .regime(2.0, None, Some(array![[-1.0, 0.0], [0.0, -1.0]]));
+        assert!(forcing.validate(2).is_ok());
+
+        let (g0, v0) = forcing.parameters_at(0.5, &g, &v);
+        let (g1, _) = forcing.parameters_at(1.0, &g, &v);
+        let (g2, v2) = forcing.parameters_at(2.5, &g, &v);
+        assert_eq!((g0, v0[(0, 0)]), (array![1.0, 1.0], 0.0));
+        assert_eq!(g1, array![2.0, 0.0]);
+        assert_eq!((g2, v2[(1, 1)]), (array![1.0, 1.0], -1.0));
+
+        let unordered = Forcing::piecewise()
+            .regime(2.0, None, None)
+            .regime(1.0, None, None);
+        assert!(unordered.validate(2).is_err());
+        assert!(
+            Forcing::seasonal(array![1.0], 1.0, 0.0)
+                .validate(2)
+                .is_err()
+        );
+    }
+}
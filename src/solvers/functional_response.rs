@@ -0,0 +1,396 @@
+/*!
+Saturating functional responses for GLV interactions.
+
+Purpose:
+    Linear GLV terms `V_ij n_j` grow without bound in the partner density,
+    which makes consumer–resource modules blow up. `FunctionalResponse`
+    replaces each cross-species term with a Holling type II or type III
+    response with its own handling time `h_ij`; `HollingGlv` is the resulting
+    population reaction, usable by the well-mixed and spatial solvers.
+
+Response contract:
+    The response always saturates in the resource's density. A pair with
+    `V_ij >= 0` makes `i` the consumer of `j`, and `i` gains
+    `V_ij φ(n_j; h_ij)`. A pair with `V_ij < 0` makes `i` the resource of
+    `j`, and `i` loses `V_ij n_j ψ(n_i; h_ji)` per capita, so the flux
+    `n_i V_ij n_j ψ(n_i; h_ji) = V_ij n_j φ(n_i; h_ji)` matches the
+    consumer's intake. Here `φ(x; h) = x^q / (1 + h x^q)` and
+    `ψ(x; h) = φ(x; h) / x`, with `q = 1` (type II) or `q = 2` (type III).
+    `h_ij` is the handling time of consumer `i` on resource `j`.
+    Self-interaction stays linear and the diagonal of `handling_times` is
+    ignored. Negative stage values are read as zero, with zero slope.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use crate::solvers::dynamics::Dynamics;
+
+/// Holling response of cross-species GLV terms, with per-pair handling times.
+///
+/// Details:
+/// - Variants:
+///   - `HollingII`: `φ(x) = x / (1 + h x)`; zero handling times recover
+///     linear GLV.
+///   - `HollingIII`: `φ(x) = x² / (1 + h x²)`, sigmoid at low density.
+/// - Parameters:
+///   - `handling_times`: Nonnegative `d×d` matrix `h`; `h_ij` is the handling
+///     time of consumer `i` on resource `j`, saturating its gain at
+///     `V_ij / h_ij` and the matching loss of `j` in `n_j`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum FunctionalResponse {
+    HollingII { handling_times: Array2<f64> },
+    HollingIII { handling_times: Array2<f64> },
+}
+
+impl FunctionalResponse {
+    #[inline]
+    pub fn holling_ii(handling_times: Array2<f64>) -> Self {
+        Self::HollingII { handling_times }
+    }
+
+    #[inline]
+    pub fn holling_iii(handling_times: Array2<f64>) -> Self {
+        Self::HollingIII { handling_times }
+    }
+
+    #[inline]
+    pub fn handling_times(&self) -> &Array2<f64> {
+        match self {
+            Self::HollingII { handling_times } | Self::HollingIII { handling_times } => {
+                handling_times
+            }
+        }
+    }
+
+    /// `φ(x; h)` and `dφ/dx` for one pair; negative `x` gives `(0, 0)`.
+    #[inline]
+    fn response(&self, x: f64, h: f64) -> (f64, f64) {
+        if x < 0.0 {
+            return (0.0, 0.0);
+        }
+        match self {
+            Self::HollingII { .. } => {
+                let denom = 1.0 + h * x;
+                (x / denom, 1.0 / (denom * denom))
+            }
+            Self::HollingIII { .. } => {
+                let x2 = x * x;
+                let denom = 1.0 + h * x2;
+                (x2 / denom, 2.0 * x / (denom * denom))
+            }
+        }
+    }
+
+    /// `ψ(x; h) = φ(x; h) / x` and `dψ/dx`; negative `x` reads as zero with
+    /// zero slope.
+    #[inline]
+    fn intake(&self, x: f64, h: f64) -> (f64, f64) {
+        let (x, scale) = if x < 0.0 { (0.0, 0.0) } else { (x, 1.0) };
+        match self {
+            Self::HollingII { .. } => {
+                let denom = 1.0 + h * x;
+                (1.0 / denom, -scale * h / (denom * denom))
+            }
+            Self::HollingIII { .. } => {
+                let x2 = x * x;
+                let denom = 1.0 + h * x2;
+                (x / denom, scale * (1.0 - h * x2) / (denom * denom))
+            }
+        }
+    }
+
+    /// Check that `handling_times` is a finite, nonnegative `d×d` matrix.
+    pub(crate) fn validate(&self, d: usize) -> Result<()> {
+        let handling_times = self.handling_times();
+        if handling_times.dim() != (d, d) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "handling_times must be square with size matching the state",
+            ));
+        }
+        if handling_times.iter().any(|h| !h.is_finite() || *h < 0.0) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "handling_times entries must be finite and nonnegative",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// GLV population dynamics with saturating cross-species interactions.
+///
+/// Details:
+/// - Purpose: Evaluates `n_i f_i(n)` with the `FunctionalResponse` fitness,
+///   oriented by the sign of `V_ij`, and provides an analytic Jacobian.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Growth vector `g`.
+///   - `response`: Holling type and handling times.
+#[derive(Clone, Copy)]
+pub struct HollingGlv<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+    pub response: &'a FunctionalResponse,
+}
+
+impl HollingGlv<'_> {
+    /// Write the saturated fitness `f(n)` into `out`.
+    #[inline]
+    fn fitness(&self, u: &[f64], out: &mut [f64]) {
+        let v = self.interaction_matrix;
+        let h = self.response.handling_times();
+
+        for i in 0..u.len() {
+            let mut acc = self.growth_vector[i] + v[(i, i)] * u[i];
+            for j in 0..u.len() {
+                if j == i {
+                    continue;
+                }
+                let vij = v[(i, j)];
+                acc += if vij >= 0.0 {
+                    // Consumer i saturates in its resource n_j.
+                    vij * self.response.response(u[j], h[(i, j)]).0
+                } else {
+                    // Resource i is taken by consumer j, saturating in n_i.
+                    vij * u[j] * self.response.intake(u[i], h[(j, i)]).0
+                };
+            }
+            out[i] = acc;
+        }
+    }
+}
+
+impl Dynamics for HollingGlv<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        // out = n ⊙ f(n)
+        self.fitness(u, out);
+        for i in 0..u.len() {
+            out[i] *= u[i];
+        }
+    }
+
+    /// `J_ij = δ_ij f_i + n_i ∂f_i/∂n_j`. For `j ≠ i`, `∂f_i/∂n_j` is
+    /// `V_ij φ'(n_j; h_ij)` (consumer) or `V_ij ψ(n_i; h_ji)` (resource);
+    /// `∂f_i/∂n_i = V_ii + Σ_{j≠i, V_ij<0} V_ij n_j ψ'(n_i; h_ji)`.
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = u.len();
+        let v = self.interaction_matrix;
+        let h = self.response.handling_times();
+
+        let mut fitness = vec![0.0; d];
+        self.fitness(u, &mut fitness);
+
+        for i in 0..d {
+            let mut self_slope = v[(i, i)];
+            for j in 0..d {
+                if j == i {
+                    continue;
+                }
+                let vij = v[(i, j)];
+                let slope = if vij >= 0.0 {
+                    vij * self.response.response(u[j], h[(i, j)]).1
+                } else {
+                    let (intake, intake_slope) = self.response.intake(u[i], h[(j, i)]);
+                    self_slope += vij * u[j] * intake_slope;
+                    vij * intake
+                };
+                out[(i, j)] = u[i] * slope;
+            }
+            out[(i, i)] = fitness[i] + u[i] * self_slope;
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::dynamics::GlvPopulation;
+    use crate::solvers::integrator::Rk4;
+    use crate::solvers::non_spatial::noise::Noise;
+    use crate::solvers::non_spatial::rk4::solve_glv_with_functional_response;
+    use crate::solvers::rng::RngSeed;
+    use crate::solvers::termination::TerminationConfig;
+    use crate::{Mode, SystemState};
+    use ndarray::array;
+
+    #[test]
+    fn holling_jacobians_match_finite_differences_and_type_ii_reduces_to_glv() {
+        let g = array![1.0, -0.2, -0.1];
+        let v = array![[-1.0, -2.0, 0.0], [1.5, -0.1, -0.8], [0.0, 0.6, -0.05]];
+        let h = array![[0.0, 0.5, 0.0], [0.5, 0.0, 2.0], [0.0, 2.0, 0.0]];
+        let u = [1.2, 0.4, 0.7];
+
+        for response in [
+            FunctionalResponse::holling_ii(h.clone()),
+            FunctionalResponse::holling_iii(h.clone()),
+        ] {
+            let dynamics = HollingGlv {
+                interaction_matrix: &v,
+                growth_vector: &g,
+                response: &response,
+            };
+            let mut jac = Array2::zeros((3, 3));
+            assert!(dynamics.jacobian(&u, &mut jac));
+
+            let eps = 1e-7;
+            let mut f_plus = [0.0; 3];
+            let mut f_minus = [0.0; 3];
+            for j in 0..3 {
+                let mut plus = u;
+                let mut minus = u;
+                plus[j] += eps;
+                minus[j] -= eps;
+                dynamics.reaction(&plus, &mut f_plus);
+                dynamics.reaction(&minus, &mut f_minus);
+                for i in 0..3 {
+                    let fd = (f_plus[i] - f_minus[i]) / (2.0 * eps);
+                    assert!((jac[(i, j)] - fd).abs() < 1e-6);
+                }
+            }
+        }
+
+        let linear = FunctionalResponse::holling_ii(Array2::zeros((3, 3)));
+        let mut holling = [0.0; 3];
+        let mut glv = [0.0; 3];
+        HollingGlv {
+            interaction_matrix: &v,
+            growth_vector: &g,
+            response: &linear,
+        }
+        .reaction(&u, &mut holling);
+        GlvPopulation {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        }
+        .reaction(&u, &mut glv);
+        for i in 0..3 {
+            assert!((holling[i] - glv[i]).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn rosenzweig_macarthur_module_settles_at_the_closed_form_equilibrium() {
+        // Prey x: x (r (1 - x/K) - a y / (1 + a T x)).
+        // Predator y: y (e a x / (1 + a T x) - m).
+        let (r, k, a, t, e, m) = (1.0, 2.0, 1.0, 0.5, 0.5, 0.2);
+        let g = array![r, -m];
+        let v = array![[-r / k, -a], [e * a, 0.0]];
+        let response = FunctionalResponse::holling_ii(array![[0.0, 0.0], [a * t, 0.0]]);
+        let x_star = m / (a * (e - m * t));
+        let y_star = r / a * (1.0 - x_star / k) * (1.0 + a * t * x_star);
+
+        let dynamics = HollingGlv {
+            interaction_matrix: &v,
+            growth_vector: &g,
+            response: &response,
+        };
+        let mut out = [0.0; 2];
+        dynamics.reaction(&[x_star, y_star], &mut out);
+        assert!(out.iter().all(|f| f.abs() < 1e-14));
+
+        let output_path = std::env::temp_dir().join(format!(
+            "glv_functional_response_rosenzweig_macarthur_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&output_path);
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![1.0, 0.5],
+            None,
+        );
+        let outcome = solve_glv_with_functional_response(
+            gs,
+            &v,
+            Some(&g),
+            &response,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            40_000,
+            20_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+        let state = outcome.final_state.state;
+        assert!((state[0] - x_star).abs() < 1e-6);
+        assert!((state[1] - y_star).abs() < 1e-6);
+
+        // V, g, and h are checked against a three-species state.
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![1.0, 0.5, 0.5],
+            None,
+        );
+        let err = solve_glv_with_functional_response(
+            gs,
+            &v,
+            Some(&g),
+            &response,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .err()
+        .expect("shape mismatch rejected");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        let _ = std::fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn cross_terms_saturate_at_v_over_h() {
+        let g = array![0.0, 0.0];
+        let v = array![[0.0, 3.0], [0.0, 0.0]];
+        let response = FunctionalResponse::holling_iii(array![[0.0, 0.5], [0.0, 0.0]]);
+        let dynamics = HollingGlv {
+            interaction_matrix: &v,
+            growth_vector: &g,
+            response: &response,
+        };
+
+        let mut out = [0.0; 2];
+        dynamics.reaction(&[1.0, 1e6], &mut out);
+        assert!((out[0] - 6.0).abs() < 1e-9);
+
+        // Negative stage values read as zero, with zero slope.
+        for response in [
+            FunctionalResponse::holling_ii(Array2::zeros((1, 1))),
+            FunctionalResponse::holling_iii(Array2::zeros((1, 1))),
+        ] {
+            assert_eq!(response.response(-0.5, 1.0), (0.0, 0.0));
+            assert_eq!(response.intake(-0.5, 1.0).1, 0.0);
+        }
+
+        assert!(response.validate(2).is_ok());
+        assert!(response.validate(3).is_err());
+        assert!(
+            FunctionalResponse::holling_ii(array![[0.0, -1.0], [0.0, 0.0]])
+                .validate(2)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,358 @@
+/*!
+Third-order (three-body) interactions.
+
+Purpose:
+    `InteractionTensor` stores `B_ijk`, the effect of the pair `(j, k)` on the
+    fitness of species `i`, either densely or as a list of nonzero entries.
+    `HigherOrder` adds `Σ_jk B_ijk u_j u_k` to the pairwise fitness
+    `g + Vu` of the built-in replicator or GLV reaction, so the same term is
+    available to every well-mixed and spatial solver.
+
+Fitness contract:
+    `f_i(u) = g_i + (Vu)_i + Σ_jk B_ijk u_j u_k`. GLV states evolve as
+    `n_i f_i(n)`; replicator states as `ν_i (f_i(ν) - Υ)` with
+    `Υ = Σ_i ν_i f_i(ν)`, which keeps the simplex invariant.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use ndarray::{Array1, Array2, Array3};
+use serde::{Deserialize, Serialize};
+
+use crate::solvers::dynamics::{Dynamics, fitness_inplace};
+
+/// One nonzero entry `B_ijk` of a sparse tensor.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct TensorEntry {
+    pub i: usize,
+    pub j: usize,
+    pub k: usize,
+    pub value: f64,
+}
+
+/// Third-order interaction tensor `B`.
+///
+/// Details:
+/// - Variants:
+///   - `Dense`: `d×d×d` array indexed `[i, j, k]`.
+///   - `Sparse`: Nonzero entries over `num_species` species; repeated
+///     indices add up.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum InteractionTensor {
+    Dense(Array3<f64>),
+    Sparse {
+        num_species: usize,
+        entries: Vec<TensorEntry>,
+    },
+}
+
+impl InteractionTensor {
+    #[inline]
+    pub fn dense(tensor: Array3<f64>) -> Self {
+        Self::Dense(tensor)
+    }
+
+    /// Empty sparse tensor; add entries with `entry`.
+    #[inline]
+    pub fn sparse(num_species: usize) -> Self {
+        Self::Sparse {
+            num_species,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Append `B_ijk = value`. No-op for `Dense`.
+    pub fn entry(mut self, i: usize, j: usize, k: usize, value: f64) -> Self {
+        if let Self::Sparse { entries, .. } = &mut self {
+            entries.push(TensorEntry { i, j, k, value });
+        }
+        self
+    }
+
+    /// Length of each tensor axis.
+    #[inline]
+    pub fn num_species(&self) -> usize {
+        match self {
+            Self::Dense(tensor) => tensor.dim().0,
+            Self::Sparse { num_species, .. } => *num_species,
+        }
+    }
+
+    /// Check that every axis matches `d` species and that entries are finite.
+    pub(crate) fn validate(&self, d: usize) -> Result<()> {
+        let invalid = |message: &str| Error::new(ErrorKind::InvalidInput, message.to_owned());
+
+        match self {
+            Self::Dense(tensor) => {
+                if tensor.dim() != (d, d, d) {
+                    return Err(invalid(
+                        "interaction tensor must be d×d×d with d matching the species axis",
+                    ));
+                }
+                if tensor.iter().any(|b| !b.is_finite()) {
+                    return Err(invalid("interaction tensor entries must be finite"));
+                }
+            }
+            Self::Sparse {
+                num_species,
+                entries,
+            } => {
+                if *num_species != d {
+                    return Err(invalid(
+                        "interaction tensor num_species must match the species axis",
+                    ));
+                }
+                if entries.iter().any(|e| e.i >= d || e.j >= d || e.k >= d) {
+                    return Err(invalid(
+                        "interaction tensor index is outside the species axis",
+                    ));
+                }
+                if entries.iter().any(|e| !e.value.is_finite()) {
+                    return Err(invalid("interaction tensor entries must be finite"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Call `visit(i, j, k, B_ijk)` for every stored entry.
+    #[inline]
+    fn for_each(&self, mut visit: impl FnMut(usize, usize, usize, f64)) {
+        match self {
+            Self::Dense(tensor) => {
+                for ((i, j, k), &b) in tensor.indexed_iter() {
+                    if b != 0.0 {
+                        visit(i, j, k, b);
+                    }
+                }
+            }
+            Self::Sparse { entries, .. } => {
+                for e in entries {
+                    visit(e.i, e.j, e.k, e.value);
+                }
+            }
+        }
+    }
+
+    /// Add `Σ_jk B_ijk u_j u_k` to `out_i`.
+    #[inline]
+    pub fn add_to_fitness(&self, u: &[f64], out: &mut [f64]) {
+        self.for_each(|i, j, k, b| out[i] += b * u[j] * u[k]);
+    }
+
+    /// Add `∂/∂u_j Σ_kl B_ikl u_k u_l = Σ_k (B_ijk + B_ikj) u_k` to `out_ij`.
+    #[inline]
+    pub fn add_to_fitness_jacobian(&self, u: &[f64], out: &mut Array2<f64>) {
+        self.for_each(|i, j, k, b| {
+            out[(i, j)] += b * u[k];
+            out[(i, k)] += b * u[j];
+        });
+    }
+}
+
+/// Built-in replicator or GLV reaction with third-order interactions.
+///
+/// Details:
+/// - Purpose: Evaluates `ν_i (f_i - Υ)` (`frequency`) or `n_i f_i` with
+///   `f = g + Vu + B(u, u)`, and an analytic Jacobian.
+/// - Parameters:
+///   - `interaction_matrix`: Square pairwise interaction matrix `V`.
+///   - `growth_vector`: Growth vector `g`.
+///   - `tensor`: Third-order interaction tensor `B`.
+///   - `frequency`: Replicator (`true`) or GLV (`false`) reaction.
+#[derive(Clone, Copy)]
+pub struct HigherOrder<'a> {
+    pub interaction_matrix: &'a Array2<f64>,
+    pub growth_vector: &'a Array1<f64>,
+    pub tensor: &'a InteractionTensor,
+    pub frequency: bool,
+}
+
+impl<'a> HigherOrder<'a> {
+    #[inline]
+    pub fn replicator(
+        interaction_matrix: &'a Array2<f64>,
+        growth_vector: &'a Array1<f64>,
+        tensor: &'a InteractionTensor,
+    ) -> Self {
+        Self {
+            interaction_matrix,
+            growth_vector,
+            tensor,
+            frequency: true,
+        }
+    }
+
+    #[inline]
+    pub fn glv(
+        interaction_matrix: &'a Array2<f64>,
+        growth_vector: &'a Array1<f64>,
+        tensor: &'a InteractionTensor,
+    ) -> Self {
+        Self {
+            interaction_matrix,
+            growth_vector,
+            tensor,
+            frequency: false,
+        }
+    }
+
+    /// Write `f = g + Vu + B(u, u)` into `out`.
+    #[inline]
+    fn fitness(&self, u: &[f64], out: &mut [f64]) {
+        fitness_inplace(u, self.growth_vector, self.interaction_matrix, out);
+        self.tensor.add_to_fitness(u, out);
+    }
+}
+
+impl Dynamics for HigherOrder<'_> {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        self.fitness(u, out);
+
+        let upsilon = if self.frequency {
+            u.iter().zip(out.iter()).map(|(x, f)| x * f).sum()
+        } else {
+            0.0
+        };
+        for i in 0..u.len() {
+            out[i] = u[i] * (out[i] - upsilon);
+        }
+    }
+
+    /// `f_i`, minus `Υ` for the replicator.
+    fn per_capita_at(&self, _t: f64, u: &[f64], out: &mut [f64]) {
+        self.fitness(u, out);
+        if self.frequency {
+            let upsilon: f64 = u.iter().zip(out.iter()).map(|(x, f)| x * f).sum();
+            for f in out.iter_mut() {
+                *f -= upsilon;
+            }
+        }
+    }
+
+    /// `J_ij = δ_ij (f_i - Υ) + u_i (F_ij - ∂Υ/∂u_j)`, where
+    /// `F_ij = V_ij + Σ_k (B_ijk + B_ikj) u_k` and, for the replicator,
+    /// `∂Υ/∂ν_j = f_j + Σ_k ν_k F_kj` (both `Υ` terms vanish for GLV).
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = u.len();
+
+        let mut fitness = vec![0.0; d];
+        self.fitness(u, &mut fitness);
+        let mut gradient = self.interaction_matrix.clone();
+        self.tensor.add_to_fitness_jacobian(u, &mut gradient);
+
+        let mut upsilon = 0.0;
+        let mut col = vec![0.0; d];
+        if self.frequency {
+            for i in 0..d {
+                upsilon += u[i] * fitness[i];
+            }
+            for j in 0..d {
+                let mut acc = 0.0;
+                for k in 0..d {
+                    acc += u[k] * gradient[(k, j)];
+                }
+                col[j] = fitness[j] + acc;
+            }
+        }
+
+        for i in 0..d {
+            for j in 0..d {
+                out[(i, j)] = u[i] * (gradient[(i, j)] - col[j]);
+            }
+            out[(i, i)] += fitness[i] - upsilon;
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn example_tensors() -> (InteractionTensor, InteractionTensor) {
+        let sparse = InteractionTensor::sparse(3)
+            .entry(0, 1, 2, 0.8)
+            .entry(1, 0, 0, -0.4)
+            .entry(2, 2, 1, 0.3)
+            .entry(2, 2, 1, -0.1);
+        let mut dense = Array3::zeros((3, 3, 3));
+        dense[(0, 1, 2)] = 0.8;
+        dense[(1, 0, 0)] = -0.4;
+        dense[(2, 2, 1)] = 0.2;
+        (InteractionTensor::dense(dense), sparse)
+    }
+
+    #[test]
+    fn dense_and_sparse_tensors_agree_and_jacobians_match_finite_differences() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+        let (dense, sparse) = example_tensors();
+
+        for (frequency, u) in [(true, [0.2, 0.3, 0.5]), (false, [1.5, 0.3, 2.0])] {
+            let dense = HigherOrder {
+                interaction_matrix: &v,
+                growth_vector: &g,
+                tensor: &dense,
+                frequency,
+            };
+            let sparse = HigherOrder {
+                tensor: &sparse,
+                ..dense
+            };
+
+            let mut from_dense = [0.0; 3];
+            let mut from_sparse = [0.0; 3];
+            dense.reaction(&u, &mut from_dense);
+            sparse.reaction(&u, &mut from_sparse);
+            for i in 0..3 {
+                assert!((from_dense[i] - from_sparse[i]).abs() < 1e-14);
+            }
+            if frequency {
+                assert!(from_dense.iter().sum::<f64>().abs() < 1e-12);
+            }
+
+            let mut jac = Array2::zeros((3, 3));
+            assert!(sparse.jacobian(&u, &mut jac));
+            let eps = 1e-7;
+            let mut f_plus = [0.0; 3];
+            let mut f_minus = [0.0; 3];
+            for j in 0..3 {
+                let mut plus = u;
+                let mut minus = u;
+                plus[j] += eps;
+                minus[j] -= eps;
+                sparse.reaction(&plus, &mut f_plus);
+                sparse.reaction(&minus, &mut f_minus);
+                for i in 0..3 {
+                    let fd = (f_plus[i] - f_minus[i]) / (2.0 * eps);
+                    assert!((jac[(i, j)] - fd).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tensors_are_validated_against_the_species_axis() {
+        let (dense, sparse) = example_tensors();
+        assert!(dense.validate(3).is_ok());
+        assert!(sparse.validate(3).is_ok());
+        assert!(dense.validate(2).is_err());
+        assert!(sparse.validate(4).is_err());
+        assert!(
+            InteractionTensor::sparse(2)
+                .entry(0, 2, 1, 1.0)
+                .validate(2)
+                .is_err()
+        );
+        assert!(
+            InteractionTensor::dense(Array3::zeros((2, 2, 3)))
+                .validate(2)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+/*!
+Matrix–vector kernels for the built-in reaction terms.
+
+Purpose:
+    The pairwise fitness `g + Vu` dominates the cost of large-`d` well-mixed
+    runs. `affine_matvec_inplace` walks contiguous row slices with `LANES`
+    independent accumulators, which the compiler turns into SIMD code, and
+    spreads rows over rayon once `d` reaches `PARALLEL_ROWS_THRESHOLD`.
+
+Determinism contract:
+    Column `j` always accumulates into lane `j % LANES`, and lanes are reduced
+    in a fixed order, so results do not depend on the thread count. The sparse
+    kernel uses the same lane assignment and reproduces the dense result.
+*/
+
+use ndarray::{Array1, Array2};
+use rayon::prelude::*;
+
+/// Independent accumulators per row.
+pub(crate) const LANES: usize = 8;
+
+/// Row count from which rows are evaluated in parallel.
+pub(crate) const PARALLEL_ROWS_THRESHOLD: usize = 256;
+
+/// Sum lanes in a fixed pairwise order.
+#[inline]
+pub(crate) fn reduce_lanes(lanes: [f64; LANES]) -> f64 {
+    ((lanes[0] + lanes[4]) + (lanes[2] + lanes[6]))
+        + ((lanes[1] + lanes[5]) + (lanes[3] + lanes[7]))
+}
+
+/// `Σ_j row_j x_j` over contiguous slices with `LANES` accumulators.
+#[inline]
+fn dot(row: &[f64], x: &[f64]) -> f64 {
+    let mut lanes = [0.0; LANES];
+    let (row_chunks, row_tail) = row.as_chunks::<LANES>();
+    let (x_chunks, x_tail) = x.as_chunks::<LANES>();
+    for (r, v) in row_chunks.iter().zip(x_chunks) {
+        for l in 0..LANES {
+            lanes[l] += r[l] * v[l];
+        }
+    }
+    for (l, (r, v)) in row_tail.iter().zip(x_tail).enumerate() {
+        lanes[l] += r * v;
+    }
+    reduce_lanes(lanes)
+}
+
+/// Write `f(i)` into `out[i]` for every row, in parallel for large `out`.
+#[inline]
+pub(crate) fn fill_rows(out: &mut [f64], f: impl Fn(usize) -> f64 + Sync) {
+    if out.len() >= PARALLEL_ROWS_THRESHOLD {
+        out.par_iter_mut().enumerate().for_each(|(i, o)| *o = f(i));
+    } else {
+        for (i, o) in out.iter_mut().enumerate() {
+            *o = f(i);
+        }
+    }
+}
+
+/// `Σ_j matrix_ij x_j` for row `i`.
+///
+/// Details:
+/// - Purpose: Uses the contiguous row slice when `matrix` is in standard
+///   layout; other layouts fall back to a strided row with the same lane
+///   assignment, so both give identical results.
+#[inline]
+pub(crate) fn row_dot(matrix: &Array2<f64>, i: usize, x: &[f64]) -> f64 {
+    let row = matrix.row(i);
+    match row.as_slice() {
+        Some(row) => dot(row, x),
+        None => {
+            let mut lanes = [0.0; LANES];
+            for (j, (m, v)) in row.iter().zip(x).enumerate() {
+                lanes[j % LANES] += m * v;
+            }
+            reduce_lanes(lanes)
+        }
+    }
+}
+
+/// Add `a` times row `i` of `matrix` to `out`.
+#[inline]
+pub(crate) fn add_scaled_row(matrix: &Array2<f64>, i: usize, a: f64, out: &mut [f64]) {
+    let row = matrix.row(i);
+    match row.as_slice() {
+        Some(row) => {
+            for (o, m) in out.iter_mut().zip(row) {
+                *o += a * m;
+            }
+        }
+        None => {
+            for (o, m) in out.iter_mut().zip(row.iter()) {
+                *o += a * m;
+            }
+        }
+    }
+}
+
+/// Write `offset + matrix x` into `out`, row by row through `row_dot`.
+#[inline]
+pub(crate) fn affine_matvec_inplace(
+    matrix: &Array2<f64>, // M (d×d)
+    x: &[f64],            // input (len d)
+    offset: &Array1<f64>, // added after the product (len d)
+    out: &mut [f64],      // output: offset + Mx
+) {
+    fill_rows(out, |i| offset[i] + row_dot(matrix, i, x));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::ShapeBuilder;
+
+    #[test]
+    fn lane_kernel_matches_the_scalar_loop_across_layouts_and_thresholds() {
+        for d in [3, 17, PARALLEL_ROWS_THRESHOLD + 5] {
+            let matrix =
+                Array2::from_shape_fn((d, d), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+            let x: Vec<f64> = (0..d).map(|j| (j as f64 * 0.37).sin()).collect();
+            let offset = Array1::from_shape_fn(d, |i| i as f64 * 0.01);
+
+            let mut expected = vec![0.0; d];
+            for i in 0..d {
+                let mut acc = offset[i];
+                for j in 0..d {
+                    acc += matrix[(i, j)] * x[j];
+                }
+                expected[i] = acc;
+            }
+
+            let mut contiguous = vec![0.0; d];
+            affine_matvec_inplace(&matrix, &x, &offset, &mut contiguous);
+            let mut fortran = Array2::zeros((d, d).f());
+            fortran.assign(&matrix);
+            assert!(fortran.row(0).as_slice().is_none());
+            let mut strided = vec![0.0; d];
+            affine_matvec_inplace(&fortran, &x, &offset, &mut strided);
+
+            assert_eq!(contiguous, strided);
+            for i in 0..d {
+                assert!((contiguous[i] - expected[i]).abs() < 1e-9);
+            }
+
+            let mut contiguous = x.clone();
+            let mut strided = x.clone();
+            add_scaled_row(&matrix, 1, -0.5, &mut contiguous);
+            add_scaled_row(&fortran, 1, -0.5, &mut strided);
+            assert_eq!(contiguous, strided);
+            assert_eq!(contiguous[2], x[2] - 0.5 * matrix[(1, 2)]);
+        }
+    }
+}
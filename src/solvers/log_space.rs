@@ -0,0 +1,231 @@
+/*!
+Log-space coordinates for well-mixed runs.
+
+Purpose:
+    Random replicator and GLV communities push rare species to abundances of
+    `1e-30` and below, where a linear step plus the hard `cutoff` produces
+    spurious extinctions. `LogSpaceField` integrates `x_i = ln u_i` instead,
+    with `dx_i/dt = f_i(u) / u_i` from `Dynamics::per_capita_at`, so
+    abundances stay exactly positive however small they get.
+
+Coordinate contract:
+    Frequency states are recovered as `ν_i = exp(x_i - LSE(x))`, with the
+    log-sum-exp `LSE(x) = ln Σ_j exp(x_j)` evaluated around `max_j x_j`.
+    Population states are `n_i = exp(x_i)`; a carrying capacity `K` shifts
+    every `x_i` by `ln K - LSE(x)` when the total exceeds `K`. Species that
+    start at zero sit at `x_i = -∞` and stay extinct. A step that produces
+    `NaN`, `+∞`, or an abundance beyond `f64::MAX` is a numerical blow-up,
+    not an extinction, and fails with `ErrorKind::InvalidData`.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use ndarray::Array2;
+
+use crate::solvers::dynamics::Dynamics;
+use crate::solvers::integrator::VectorField;
+use crate::{Mode, SystemState};
+
+/// `ln Σ_j exp(x_j)`, shifted by `max_j x_j` so no term overflows.
+///
+/// Returns `-∞` when every entry is `-∞` (or `x` is empty).
+pub fn log_sum_exp(x: &[f64]) -> f64 {
+    let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !max.is_finite() {
+        return max;
+    }
+    max + x.iter().map(|v| (v - max).exp()).sum::<f64>().ln()
+}
+
+/// Write `ln u_i` into `x`; nonpositive entries map to `-∞`.
+pub fn to_log_inplace(u: &[f64], x: &mut [f64]) {
+    for (x, &u) in x.iter_mut().zip(u) {
+        *x = if u > 0.0 { u.ln() } else { f64::NEG_INFINITY };
+    }
+}
+
+/// Write `exp(x_i)`, or `exp(x_i - LSE(x))` when `frequency`, into `u`.
+pub fn from_log_inplace(x: &[f64], frequency: bool, u: &mut [f64]) {
+    let shift = if frequency { log_sum_exp(x) } else { 0.0 };
+    for (u, &x) in u.iter_mut().zip(x) {
+        *u = (x - shift).exp();
+    }
+}
+
+/// Restore the log-space invariants of `mode` after a raw step.
+///
+/// Details:
+/// - Purpose: Subtracts `LSE(x)` for frequency states or caps the population
+///   total at the carrying capacity. The cutoff is not applied. `NaN`, `+∞`,
+///   and entries whose `exp` overflows return `ErrorKind::InvalidData`.
+pub(crate) fn normalize_log_inplace(x: &mut [f64], mode: &Mode<f64>) -> Result<()> {
+    if x.iter().any(|v| v.is_nan() || *v == f64::INFINITY) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "log-space step produced NaN or +inf; reduce dt",
+        ));
+    }
+
+    let shift = match *mode {
+        Mode::Frequency { .. } => log_sum_exp(x),
+        Mode::Population {
+            carrying_capacity: Some(capacity),
+            ..
+        } if capacity > 0.0 => (log_sum_exp(x) - capacity.ln()).max(0.0),
+        Mode::Population { .. } => 0.0,
+    };
+    if shift.is_finite() && shift != 0.0 {
+        for v in x.iter_mut() {
+            *v -= shift;
+        }
+    }
+
+    if x.iter().any(|v| *v > f64::MAX.ln()) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "log-space abundance overflows f64; the population diverged",
+        ));
+    }
+    Ok(())
+}
+
+/// `sanitize` with the cutoff of `gs.mode` skipped.
+///
+/// Details:
+/// - Purpose: Refreshes the cached mass (and the invariants `exp(x)` already
+///   satisfies) while leaving `gs.mode` as the caller passed it.
+pub(crate) fn sanitize_without_cutoff(gs: &mut SystemState<f64>) {
+    let mode = match gs.mode {
+        Mode::Frequency { .. } => Mode::Frequency { cutoff: None },
+        Mode::Population {
+            carrying_capacity, ..
+        } => Mode::Population {
+            cutoff: None,
+            carrying_capacity,
+        },
+    };
+    let mode = std::mem::replace(&mut gs.mode, mode);
+    gs.sanitize();
+    gs.mode = mode;
+}
+
+/// Log-space vector field for the shared integrators.
+///
+/// Details:
+/// - Purpose: Evaluates `dx_i/dt = f_i(u) / u_i` at `u` recovered from `x`.
+///   No Jacobian is provided, so implicit integrators reject the field.
+/// - Parameters:
+///   - `dynamics`: Reaction term in linear coordinates.
+///   - `frequency`: Recover `u` with the log-sum-exp normalisation.
+///   - `time`: Step start time, used by integrators that call `eval`.
+///   - `abundances`: Scratch buffer for `u`.
+pub(crate) struct LogSpaceField<'a> {
+    pub(crate) dynamics: &'a dyn Dynamics,
+    pub(crate) frequency: bool,
+    pub(crate) time: f64,
+    pub(crate) abundances: Vec<f64>,
+}
+
+impl<'a> LogSpaceField<'a> {
+    pub(crate) fn new(dynamics: &'a dyn Dynamics, frequency: bool, d: usize) -> Self {
+        Self {
+            dynamics,
+            frequency,
+            time: 0.0,
+            abundances: vec![0.0; d],
+        }
+    }
+}
+
+impl VectorField for LogSpaceField<'_> {
+    #[inline]
+    fn eval(&mut self, x: &[f64], out: &mut [f64]) -> Result<()> {
+        self.eval_at(self.time, x, out)
+    }
+
+    #[inline]
+    fn eval_at(&mut self, t: f64, x: &[f64], out: &mut [f64]) -> Result<()> {
+        from_log_inplace(x, self.frequency, &mut self.abundances);
+        self.dynamics.per_capita_at(t, &self.abundances, out);
+        Ok(())
+    }
+
+    fn jacobian(&mut self, _x: &[f64], _out: &mut Array2<f64>) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::dynamics::{GlvPopulation, Replicator};
+    use ndarray::array;
+
+    #[test]
+    fn log_sum_exp_is_stable_far_from_zero() {
+        assert!((log_sum_exp(&[1000.0, 1000.0]) - (1000.0 + 2f64.ln())).abs() < 1e-12);
+        assert!(
+            (log_sum_exp(&[-1000.0, -1000.0 - 3f64.ln()]) - (-1000.0 + (4.0f64 / 3.0).ln())).abs()
+                < 1e-12
+        );
+        assert_eq!(log_sum_exp(&[f64::NEG_INFINITY; 2]), f64::NEG_INFINITY);
+
+        let mut nu = [0.0; 3];
+        from_log_inplace(&[-1000.0, -1000.0, -1100.0], true, &mut nu);
+        assert!((nu[0] - 0.5).abs() < 1e-12);
+        assert!(nu[2] > 0.0 && (nu[2].ln() + 100.0 + 2f64.ln()).abs() < 1e-9);
+
+        let capped = Mode::Population {
+            cutoff: None,
+            carrying_capacity: Some(2.0),
+        };
+        let mut x = [3f64.ln(), 1f64.ln(), f64::NEG_INFINITY];
+        normalize_log_inplace(&mut x, &capped).expect("finite entries");
+        assert!((x[0].exp() - 1.5).abs() < 1e-12 && (x[1].exp() - 0.5).abs() < 1e-12);
+        assert_eq!(x[2], f64::NEG_INFINITY);
+
+        // Blow-ups are errors, not extinctions.
+        let uncapped = Mode::Population {
+            cutoff: None,
+            carrying_capacity: None,
+        };
+        for (mut x, mode) in [
+            ([0.0, f64::NAN], &capped),
+            ([0.0, f64::INFINITY], &capped),
+            ([0.0, 800.0], &uncapped),
+        ] {
+            let err = normalize_log_inplace(&mut x, mode).expect_err("blow-up rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn per_capita_rates_match_the_reaction_divided_by_the_state() {
+        let g = array![0.1, -0.2, 0.05];
+        let v = array![[0.0, 1.0, -0.5], [-1.0, 0.0, 0.3], [0.4, -0.2, -0.7]];
+        let replicator = Replicator {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        };
+        let glv = GlvPopulation {
+            interaction_matrix: &v,
+            growth_vector: &g,
+        };
+        let closure = |u: &[f64], out: &mut [f64]| glv.reaction(u, out);
+
+        let cases: [(&dyn Dynamics, [f64; 3]); 3] = [
+            (&replicator, [0.2, 0.3, 0.5]),
+            (&glv, [1.5, 0.3, 2.0]),
+            (&closure, [1.5, 0.3, 2.0]),
+        ];
+        for (dynamics, u) in cases {
+            let mut reaction = [0.0; 3];
+            let mut rate = [0.0; 3];
+            dynamics.reaction(&u, &mut reaction);
+            dynamics.per_capita_at(0.0, &u, &mut rate);
+            for i in 0..3 {
+                assert!((rate[i] - reaction[i] / u[i]).abs() < 1e-14);
+            }
+        }
+    }
+}
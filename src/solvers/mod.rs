@@ -2,11 +2,30 @@
 Solver module surface.
 
 Purpose:
-    `solvers` groups numerical evolution backends. The active implementation is
-    the non-spatial replicator solver; `spatial` is reserved for future spatial
-    evolution.
+    `solvers` groups numerical evolution backends. `dynamics` holds the public
+    per-cell reaction terms; `events` holds scheduled species introductions;
+    `extinctions` holds the per-species extinction log; `forcing` holds
+    time-dependent `g` and `V`; `functional_response` holds Holling type
+    II/III GLV interactions; `higher_order` holds third-order interaction
+    tensors; `sparse` holds CSR interaction matrices; `kernels` holds the
+    vectorised matrix–vector kernels; `log_space` holds log-coordinate
+    integration; `integrator` holds the shared fixed-step schemes;
+    `non_spatial` holds the well-mixed replicator and GLV solvers; `spatial`
+    holds arbitrary-dimensional reaction-diffusion solvers; `rng` holds the
+    seeded random streams used by stochastic solvers.
 */
 
+pub mod dynamics;
+pub mod events;
+pub mod extinctions;
+pub mod forcing;
+pub mod functional_response;
+pub mod higher_order;
+pub mod integrator;
+pub(crate) mod kernels;
+pub mod log_space;
 pub mod non_spatial;
+pub mod rng;
+pub mod sparse;
 pub mod spatial;
 pub mod termination;
@@ -0,0 +1,484 @@
+/*!
+MacArthur consumer–resource solver.
+
+Purpose:
+    This module integrates consumers `N` and explicit resources `R` in a
+    chemostat:
+    `dN_i/dt = N_i (Σ_a w_a C_ia R_a - m_i)` and
+    `dR_a/dt = δ (S_a - R_a) - R_a Σ_i C_ia N_i`.
+    Consumers live in the usual `SystemState` and are written to `signal/`;
+    resources are written to `resources/` in the same chunked format.
+    `ConsumerResource::effective_glv` derives the GLV `V` and `g` that the
+    model reduces to when resources equilibrate quickly.
+
+Evolution contract:
+    One step integrates the concatenated vector `[N; R]`, then sanitizes the
+    consumers by `SystemState.mode` and clamps resources at zero. Termination
+    checks observe the consumers only.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2, s};
+use serde::{Deserialize, Serialize};
+
+use super::rk4::WellMixedField;
+use crate::io::WriterStats;
+use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::Dynamics;
+use crate::solvers::extinctions::record_extinctions;
+use crate::solvers::integrator::Integrator;
+use crate::solvers::termination::{
+    SolveOutcome, TerminationChecker, TerminationConfig, TerminationReason,
+};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+
+/// Consumer–resource parameters.
+///
+/// Details:
+/// - Purpose: Also implements `Dynamics` over the concatenated state
+///   `[N; R]`, with an analytic Jacobian.
+/// - Parameters:
+///   - `consumption`: Uptake matrix `C` (consumers × resources, nonnegative).
+///   - `resource_values`: Conversion of resource `a` into growth, `w_a`.
+///   - `mortality`: Per-consumer loss rate `m_i`.
+///   - `supply`: Resource supply concentration `S_a`.
+///   - `dilution`: Chemostat dilution rate `δ` (positive).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConsumerResource {
+    pub consumption: Array2<f64>,
+    pub resource_values: Array1<f64>,
+    pub mortality: Array1<f64>,
+    pub supply: Array1<f64>,
+    pub dilution: f64,
+}
+
+impl ConsumerResource {
+    /// Model with unit resource values `w = 1`.
+    pub fn new(
+        consumption: Array2<f64>, // C (consumers × resources)
+        mortality: Array1<f64>,   // m
+        supply: Array1<f64>,      // S
+        dilution: f64,            // δ
+    ) -> Self {
+        let resource_values = Array1::ones(consumption.ncols());
+        Self {
+            consumption,
+            resource_values,
+            mortality,
+            supply,
+            dilution,
+        }
+    }
+
+    pub fn with_resource_values(mut self, resource_values: Array1<f64>) -> Self {
+        self.resource_values = resource_values;
+        self
+    }
+
+    #[inline]
+    pub fn num_consumers(&self) -> usize {
+        self.consumption.nrows()
+    }
+
+    #[inline]
+    pub fn num_resources(&self) -> usize {
+        self.consumption.ncols()
+    }
+
+    /// Effective GLV `(V, g)` for fast, weakly depleted resources.
+    ///
+    /// Details:
+    /// - Purpose: Resources relax to `R_a = S_a / (1 + Σ_i C_ia N_i / δ)`.
+    ///   Expanding to first order in `Σ_i C_ia N_i / δ` gives
+    ///   `g_i = Σ_a w_a C_ia S_a - m_i` and
+    ///   `V_ij = -Σ_a w_a C_ia C_ja S_a / δ`, which is exact at `N = 0` and
+    ///   accurate while consumers deplete resources only slightly.
+    pub fn effective_glv(&self) -> (Array2<f64>, Array1<f64>) {
+        let c = &self.consumption;
+        let weights = &self.resource_values * &self.supply;
+
+        let weighted = c * &weights;
+        let growth_vector = weighted.sum_axis(ndarray::Axis(1)) - &self.mortality;
+        let interaction_matrix = -weighted.dot(&c.t()) / self.dilution;
+        (interaction_matrix, growth_vector)
+    }
+
+    /// Check shapes against the consumer and resource counts and value ranges.
+    pub(crate) fn validate(&self, num_consumers: usize, num_resources: usize) -> Result<()> {
+        let invalid = |message: &str| Error::new(ErrorKind::InvalidInput, message.to_owned());
+
+        if self.consumption.dim() != (num_consumers, num_resources) {
+            return Err(invalid(
+                "consumption must be consumers × resources, matching the initial states",
+            ));
+        }
+        if self.resource_values.len() != num_resources || self.supply.len() != num_resources {
+            return Err(invalid(
+                "resource_values and supply length must match the resources",
+            ));
+        }
+        if self.mortality.len() != num_consumers {
+            return Err(invalid("mortality length must match the consumers"));
+        }
+        let nonnegative = |x: &f64| x.is_finite() && *x >= 0.0;
+        if !self.consumption.iter().all(nonnegative)
+            || !self.resource_values.iter().all(nonnegative)
+            || !self.supply.iter().all(nonnegative)
+        {
+            return Err(invalid(
+                "consumption, resource_values, and supply must be finite and nonnegative",
+            ));
+        }
+        if !self.mortality.iter().all(|x| x.is_finite()) {
+            return Err(invalid("mortality must be finite"));
+        }
+        if !self.dilution.is_finite() || self.dilution <= 0.0 {
+            return Err(invalid("dilution must be finite and positive"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Dynamics for ConsumerResource {
+    #[inline]
+    fn reaction(&self, u: &[f64], out: &mut [f64]) {
+        let d = self.num_consumers();
+        let c = &self.consumption;
+        let (consumers, resources) = u.split_at(d);
+
+        for i in 0..d {
+            let mut gain = -self.mortality[i];
+            for (a, r) in resources.iter().enumerate() {
+                gain += self.resource_values[a] * c[(i, a)] * r;
+            }
+            out[i] = consumers[i] * gain;
+        }
+        for (a, r) in resources.iter().enumerate() {
+            let mut uptake = 0.0;
+            for (i, n) in consumers.iter().enumerate() {
+                uptake += c[(i, a)] * n;
+            }
+            out[d + a] = self.dilution * (self.supply[a] - r) - r * uptake;
+        }
+    }
+
+    /// Blocks `∂Ṅ_i/∂N_i = Σ_a w_a C_ia R_a - m_i`, `∂Ṅ_i/∂R_b = N_i w_b C_ib`,
+    /// `∂Ṙ_a/∂N_j = -R_a C_ja`, and `∂Ṙ_a/∂R_a = -δ - Σ_i C_ia N_i`.
+    fn jacobian(&self, u: &[f64], out: &mut Array2<f64>) -> bool {
+        let d = self.num_consumers();
+        let c = &self.consumption;
+        let (consumers, resources) = u.split_at(d);
+
+        out.fill(0.0);
+        for i in 0..d {
+            let mut gain = -self.mortality[i];
+            for (a, r) in resources.iter().enumerate() {
+                gain += self.resource_values[a] * c[(i, a)] * r;
+                out[(i, d + a)] = consumers[i] * self.resource_values[a] * c[(i, a)];
+            }
+            out[(i, i)] = gain;
+        }
+        for (a, r) in resources.iter().enumerate() {
+            let mut uptake = 0.0;
+            for (j, n) in consumers.iter().enumerate() {
+                uptake += c[(j, a)] * n;
+                out[(d + a, j)] = -r * c[(j, a)];
+            }
+            out[(d + a, d + a)] = -self.dilution - uptake;
+        }
+
+        true
+    }
+}
+
+/// Result of a consumer–resource solve.
+///
+/// Details:
+/// - Parameters:
+///   - `consumers`: Consumer outcome; `final_state` and `signal_stats` refer
+///     to `signal/`.
+///   - `final_resources`: Resource vector at the last step.
+///   - `resource_stats`: Writer statistics of `resources/`.
+pub struct ConsumerResourceOutcome {
+    pub consumers: SolveOutcome,
+    pub final_resources: Array1<f64>,
+    pub resource_stats: WriterStats,
+}
+
+/// Integrate consumers and explicit resources and persist both signal streams.
+///
+/// Details:
+/// - Purpose: Runs the integrator -> sanitize -> snapshot loop over `[N; R]`.
+///   Consumer and resource samples share the same save steps.
+/// - Parameters:
+///   - `gs_i`: Initial consumers (`Mode::Population`).
+///   - `resources`: Initial resources (finite, nonnegative).
+///   - `model`: Consumer–resource parameters.
+///   - `integrator`: Raw step scheme over `[N; R]`.
+///   - `dt`: Step size.
+///   - `num_steps`: Number of steps.
+///   - `save_interval`: Save every Nth step to both streams.
+///   - `output_path`: Root of `signal/` and `resources/`.
+///   - `progress_counter`: Optional shared progress counter.
+///   - `termination`: Early-termination behavior on the consumers.
+#[allow(clippy::too_many_arguments)]
+pub fn solve(
+    mut gs_i: SystemState<f64>,             // initial consumers (consumed)
+    resources: Array1<f64>,                 // initial resources
+    model: &ConsumerResource,               // C, w, m, S, δ
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<ConsumerResourceOutcome> {
+    if !matches!(gs_i.mode, Mode::Population { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "consumer–resource consumers require Mode::Population",
+        ));
+    }
+    if save_interval == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "save_interval must be >= 1",
+        ));
+    }
+    let d = gs_i.state.len();
+    let m = resources.len();
+    model.validate(d, m)?;
+    if resources.iter().any(|r| !r.is_finite() || *r < 0.0) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "initial resources must be finite and nonnegative",
+        ));
+    }
+
+    // Enforce invariants at t=0; resources are clamped by their own sanitize.
+    gs_i.sanitize();
+    let resource_mode = Mode::Population {
+        cutoff: None,
+        carrying_capacity: None,
+    };
+    let mut resource_state =
+        SystemState::from_arrays(resource_mode.clone(), gs_i.time, resources, None);
+    resource_state.physical_time = gs_i.physical_time;
+    resource_state.sanitize();
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        d,
+    )?;
+    let mut resource_writer = SignalWriter::named(
+        output_path,
+        "resources",
+        resource_mode,
+        SIGNAL_OUTPUT_FILE_SIZE,
+        m,
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+    resource_writer.push(&resource_state)?;
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    // Concatenated [N; R] buffers for the integrator.
+    let mut u = Array1::zeros(d + m);
+    let mut u_next = Array1::zeros(d + m);
+    u.slice_mut(s![..d]).assign(&gs_curr.state);
+    u.slice_mut(s![d..]).assign(&resource_state.state);
+
+    let mut field = WellMixedField(model, 0.0);
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut extinctions = Vec::new();
+
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut steps_run = 0usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    for step in 1..=num_steps {
+        // Physical time at the start of this step.
+        let t = gs_curr.physical_time;
+        field.1 = t;
+        integrator.step_at(
+            &mut field,
+            t,
+            u.as_slice().expect("state is contiguous"),
+            dt,
+            u_next.as_slice_mut().expect("state is contiguous"),
+        )?;
+
+        // Split, sanitize each part, and write the sanitized values back.
+        gs_curr.state.assign(&u_next.slice(s![..d]));
+        resource_state.state.assign(&u_next.slice(s![d..]));
+        gs_curr.sanitize();
+        resource_state.sanitize();
+        gs_curr.time = start_time + step;
+        gs_curr.physical_time = start_physical_time + step as f64 * dt;
+        resource_state.time = gs_curr.time;
+        resource_state.physical_time = gs_curr.physical_time;
+        record_extinctions(
+            &u.as_slice().expect("state is contiguous")[..d],
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
+        u.slice_mut(s![..d]).assign(&gs_curr.state);
+        u.slice_mut(s![d..]).assign(&resource_state.state);
+        steps_run = step;
+
+        if step % save_interval == 0 {
+            signal_writer.push(&gs_curr)?;
+            resource_writer.push(&resource_state)?;
+        }
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        if let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            if step % save_interval != 0 {
+                signal_writer.push(&gs_curr)?;
+                resource_writer.push(&resource_state)?;
+            }
+            break;
+        }
+    }
+
+    let signal_stats = signal_writer.finish()?;
+    let resource_stats = resource_writer.finish()?;
+
+    Ok(ConsumerResourceOutcome {
+        consumers: SolveOutcome {
+            final_state: gs_curr,
+            steps_run,
+            reason: termination_reason,
+            signal_stats,
+            space_stats: None,
+            step_stats: None,
+            events: Vec::new(),
+            extinctions,
+        },
+        final_resources: resource_state.state,
+        resource_stats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::signal::load_signal_series;
+    use crate::solvers::integrator::Rk4;
+    use ndarray::array;
+    use std::fs;
+
+    fn example_model() -> ConsumerResource {
+        ConsumerResource::new(
+            array![[1.0, 0.2], [0.3, 0.8]],
+            array![1.1, 0.95],
+            array![1.0, 1.0],
+            50.0,
+        )
+        .with_resource_values(array![1.0, 0.9])
+    }
+
+    #[test]
+    fn jacobian_matches_finite_differences_and_effective_glv_is_the_weak_depletion_limit() {
+        let model = example_model();
+        let u = [0.7, 1.3, 0.4, 0.9];
+        let mut jac = Array2::zeros((4, 4));
+        assert!(model.jacobian(&u, &mut jac));
+
+        let eps = 1e-7;
+        let mut f_plus = [0.0; 4];
+        let mut f_minus = [0.0; 4];
+        for j in 0..4 {
+            let mut plus = u;
+            let mut minus = u;
+            plus[j] += eps;
+            minus[j] -= eps;
+            model.reaction(&plus, &mut f_plus);
+            model.reaction(&minus, &mut f_minus);
+            for i in 0..4 {
+                let fd = (f_plus[i] - f_minus[i]) / (2.0 * eps);
+                assert!((jac[(i, j)] - fd).abs() < 1e-6);
+            }
+        }
+
+        // V_01 = -(w_0 C_00 C_10 S_0 + w_1 C_01 C_11 S_1) / δ.
+        let (v, g) = model.effective_glv();
+        assert!((g[0] - (1.0 + 0.18 - 1.1)).abs() < 1e-12);
+        assert!((v[(0, 1)] + (0.3 + 0.9 * 0.16) / 50.0).abs() < 1e-12);
+        assert_eq!(v[(0, 1)], v[(1, 0)]);
+    }
+
+    #[test]
+    fn consumers_settle_near_the_effective_glv_equilibrium() {
+        let output_path = std::env::temp_dir().join(format!(
+            "glv_consumer_resource_equilibrium_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&output_path);
+
+        let model = example_model();
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![1.0, 1.0],
+            None,
+        );
+        let outcome = solve(
+            gs,
+            array![1.0, 1.0],
+            &model,
+            &mut Rk4::default(),
+            0.02,
+            10_000,
+            500,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        // Effective GLV equilibrium n* = -V⁻¹ g, to first order in depletion.
+        let (v, g) = model.effective_glv();
+        let det = v[(0, 0)] * v[(1, 1)] - v[(0, 1)] * v[(1, 0)];
+        let n0 = -(v[(1, 1)] * g[0] - v[(0, 1)] * g[1]) / det;
+        let n1 = -(v[(0, 0)] * g[1] - v[(1, 0)] * g[0]) / det;
+        let n = &outcome.consumers.final_state.state;
+        assert!((n[0] - n0).abs() / n0 < 0.1);
+        assert!((n[1] - n1).abs() / n1 < 0.1);
+        assert!(outcome.final_resources.iter().all(|r| *r > 0.0));
+
+        let resources =
+            load_signal_series(&output_path.join("resources/1.json")).expect("resources load");
+        assert_eq!(outcome.resource_stats.samples, 21);
+        assert_eq!(resources.samples.len(), 21);
+        let saved = &resources.samples[20].state;
+        assert!(
+            (saved - &outcome.final_resources)
+                .iter()
+                .all(|x| x.abs() < 1e-12)
+        );
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
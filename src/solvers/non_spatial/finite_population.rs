@@ -0,0 +1,598 @@
+/*!
+Well-mixed finite-population replicator solvers.
+
+Purpose:
+    This module replaces the diffusion approximation of drift in
+    `NoiseKind::DemographicGaussian` with explicit finite populations of `N`
+    individuals. Both updates use the replicator fitness `f = g + Vν` and
+    have the replicator equation `dν_i/dt = ν_i (f_i - Υ)` as their
+    large-`N` limit.
+
+Updates:
+    Wright–Fisher takes one discrete generation per step of length `dt`: the
+    next generation is a multinomial sample of `N` offspring with
+    probabilities `p_i ∝ ν_i exp(dt f_i)`. Moran runs continuous-time
+    birth–death events at total rate `N / dt`: each event copies one
+    individual chosen with weight `k_i exp(dt f_i)` over one chosen
+    uniformly, so one step holds `Poisson(N)` events.
+
+Evolution contract:
+    Integer counts `k_i` (summing to `N`) are the source of truth. After every
+    step the state is written back as `k_i / N` in `Mode::Frequency` or as
+    `k_i` in `Mode::Population`; the cutoff is not applied because extinction
+    is exact at `k_i = 0`. Fixation is absorbing, so a run always stops with
+    `TerminationReason::Monoculture` once one type remains.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2};
+use rand::{Rng, RngExt};
+use rand_distr::{Binomial, Distribution, Poisson};
+use serde::{Deserialize, Serialize};
+
+use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::{growth_vector_or_zeros, validate_interactions};
+use crate::solvers::extinctions::record_extinctions;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::{
+    SolveOutcome, TerminationChecker, TerminationConfig, TerminationReason,
+};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+
+/// Finite-population update rule.
+///
+/// Details:
+/// - Purpose: Selects how one step of length `dt` resamples the population.
+/// - Variants:
+///   - `WrightFisher`: Non-overlapping generations, multinomial resampling.
+///   - `Moran`: Overlapping generations, continuous-time birth–death events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FinitePopulationUpdate {
+    WrightFisher,
+    Moran,
+}
+
+/// Finite-population configuration (public API).
+///
+/// Details:
+/// - Purpose: Pairs an update rule with the effective population size.
+/// - Parameters:
+///   - `update`: Wright–Fisher or Moran.
+///   - `population_size`: Effective population size `N`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct FinitePopulation {
+    pub update: FinitePopulationUpdate,
+    pub population_size: usize,
+}
+
+impl FinitePopulation {
+    #[inline]
+    pub fn wright_fisher(population_size: usize) -> Self {
+        Self {
+            update: FinitePopulationUpdate::WrightFisher,
+            population_size,
+        }
+    }
+
+    #[inline]
+    pub fn moran(population_size: usize) -> Self {
+        Self {
+            update: FinitePopulationUpdate::Moran,
+            population_size,
+        }
+    }
+}
+
+/// Split `N` individuals over frequencies `nu` by largest remainder.
+fn apportion(nu: &[f64], population_size: usize, counts: &mut [f64]) {
+    let n = population_size as f64;
+    let mut assigned = 0.0;
+    for (k, &x) in counts.iter_mut().zip(nu) {
+        *k = (x * n).floor();
+        assigned += *k;
+    }
+
+    let mut order: Vec<usize> = (0..nu.len()).collect();
+    order.sort_by(|&a, &b| {
+        let ra = nu[a] * n - counts[a];
+        let rb = nu[b] * n - counts[b];
+        rb.total_cmp(&ra)
+    });
+    for &i in order.iter().take((n - assigned).max(0.0) as usize) {
+        counts[i] += 1.0;
+    }
+}
+
+/// Write the replicator fitness `f_i = g_i + (Vν)_i` for counts `k`.
+fn fitness_inplace(
+    counts: &[f64],
+    population_size: f64,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: &Array1<f64>,
+    out: &mut [f64],
+) {
+    let d = counts.len();
+    for i in 0..d {
+        let mut acc = growth_vector[i];
+        for j in 0..d {
+            acc += interaction_matrix[(i, j)] * counts[j] / population_size;
+        }
+        out[i] = acc;
+    }
+}
+
+/// One Wright–Fisher generation: multinomial sample of `N` offspring.
+fn wright_fisher_generation(
+    counts: &mut [f64],
+    fitness: &[f64],
+    population_size: usize,
+    dt: f64,
+    rng: &mut impl Rng,
+) {
+    let mut total_weight = 0.0;
+    for i in 0..counts.len() {
+        total_weight += counts[i] * (dt * fitness[i]).exp();
+    }
+
+    // Sequential binomials: k_i ~ Bin(remaining, p_i / remaining mass).
+    let mut remaining = population_size as u64;
+    let mut remaining_weight = total_weight;
+    for i in 0..counts.len() {
+        let w = counts[i] * (dt * fitness[i]).exp();
+        let drawn = if remaining == 0 || w <= 0.0 {
+            0
+        } else if w >= remaining_weight {
+            remaining
+        } else {
+            Binomial::new(remaining, (w / remaining_weight).clamp(0.0, 1.0))
+                .map(|dist| dist.sample(rng))
+                .unwrap_or(0)
+        };
+        counts[i] = drawn as f64;
+        remaining -= drawn;
+        remaining_weight -= w;
+    }
+}
+
+/// Pick index `i` with probability `mass_i / total`.
+#[inline]
+fn pick(mass: impl Iterator<Item = f64>, total: f64, rng: &mut impl Rng) -> Option<usize> {
+    let mut target = rng.random::<f64>() * total;
+    let mut last = None;
+    for (i, m) in mass.enumerate() {
+        if m > 0.0 {
+            last = Some(i);
+            if target < m {
+                return Some(i);
+            }
+            target -= m;
+        }
+    }
+    last
+}
+
+/// One step of continuous-time Moran events over time `dt`.
+///
+/// Details:
+/// - Purpose: Applies `Poisson(N)` birth–death events. Fitness is updated
+///   incrementally after each event, so one event costs O(d).
+fn moran_step(
+    counts: &mut [f64],
+    fitness: &mut [f64],
+    weights: &mut [f64],
+    population_size: usize,
+    interaction_matrix: &Array2<f64>,
+    dt: f64,
+    rng: &mut impl Rng,
+) {
+    let n = population_size as f64;
+    let events = Poisson::new(n)
+        .map(|dist| dist.sample(rng) as usize)
+        .unwrap_or(0);
+
+    for _ in 0..events {
+        let mut birth_total = 0.0;
+        for i in 0..counts.len() {
+            weights[i] = counts[i] * (dt * fitness[i]).exp();
+            birth_total += weights[i];
+        }
+        let Some(b) = pick(weights.iter().copied(), birth_total, rng) else {
+            return;
+        };
+        let Some(c) = pick(counts.iter().copied(), n, rng) else {
+            return;
+        };
+        if b == c {
+            continue;
+        }
+
+        counts[b] += 1.0;
+        counts[c] -= 1.0;
+        for i in 0..counts.len() {
+            fitness[i] += (interaction_matrix[(i, b)] - interaction_matrix[(i, c)]) / n;
+        }
+
+        if counts.iter().filter(|&&k| k > 0.0).count() <= 1 {
+            return;
+        }
+    }
+}
+
+/// Write counts back into `gs` under its mode.
+fn write_counts(gs: &mut SystemState<f64>, counts: &[f64], population_size: usize) {
+    let n = population_size as f64;
+    match gs.mode {
+        Mode::Frequency { .. } => {
+            for (x, &k) in gs.state.iter_mut().zip(counts) {
+                *x = k / n;
+            }
+            gs.mass = 1.0;
+        }
+        Mode::Population { .. } => {
+            for (x, &k) in gs.state.iter_mut().zip(counts) {
+                *x = k;
+            }
+            gs.mass = n;
+        }
+    }
+}
+
+/// Index of the single surviving type, if at most one type remains.
+#[inline]
+fn fixation(counts: &[f64]) -> Option<Option<usize>> {
+    let mut survivor = None;
+    for (i, &k) in counts.iter().enumerate() {
+        if k > 0.0 {
+            if survivor.is_some() {
+                return None;
+            }
+            survivor = Some(i);
+        }
+    }
+    Some(survivor)
+}
+
+/// Simulate a single finite-population replicator trajectory.
+///
+/// Details:
+/// - Purpose: Runs Wright–Fisher or Moran drift and selection for up to
+///   `num_steps` steps of length `dt` and writes signal snapshots.
+/// - Parameters:
+///   - `gs_i`: Initial frequency or population state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `finite_population`: Update rule and effective population size `N`.
+///   - `dt`: Generation time.
+///   - `num_steps`: Number of steps (generations).
+///   - `save_interval`: Save every Nth step; `t = 0` is always saved.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter.
+///
+/// Draws come from a fresh entropy seed; pass an explicit `RngSeed` to
+/// `solve_finite_population_with_termination` for reproducible runs.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_finite_population(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    finite_population: FinitePopulation,    // update rule and N
+    dt: f64,                                // generation time
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_finite_population_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        finite_population,
+        RngSeed::from_entropy(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Simulate a single finite-population replicator trajectory with explicit termination.
+///
+/// Details:
+/// - Purpose: Same as `solve_finite_population`, returning `SolveOutcome`.
+///   Initial frequencies are apportioned onto `N` individuals by largest
+///   remainder. Fixation stops the run with `TerminationReason::Monoculture`
+///   even when `termination` is disabled; other checks follow `termination`.
+///   All sampling draws come from `seed`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_finite_population_with_termination(
+    mut gs_i: SystemState<f64>,             // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    finite_population: FinitePopulation,    // update rule and N
+    seed: RngSeed,                          // random stream
+    dt: f64,                                // generation time
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    let population_size = finite_population.population_size;
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    if save_interval == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "save_interval must be >= 1",
+        ));
+    }
+    if population_size == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "population_size must be >= 1",
+        ));
+    }
+    if !dt.is_finite() || dt < 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "dt must be finite and nonnegative",
+        ));
+    }
+
+    // Enforce invariants at t=0, then place N individuals.
+    gs_i.sanitize();
+    let total: f64 = gs_i.state.sum();
+    if total <= 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "finite-population solvers need a positive initial state",
+        ));
+    }
+    let nu: Vec<f64> = gs_i.state.iter().map(|&x| x / total).collect();
+    let mut counts = vec![0.0; d];
+    apportion(&nu, population_size, &mut counts);
+    write_counts(&mut gs_i, &counts, population_size);
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        gs_curr.state.len(),
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let n = population_size as f64;
+    let mut fitness = vec![0.0; d];
+    let mut weights = vec![0.0; d];
+    let mut rng = seed.rng();
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut previous = vec![0.0; d];
+    let mut extinctions = Vec::new();
+
+    // Main loop: resample -> write back -> snapshot -> fixation/termination.
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut steps_run = 0usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    if let Some(surviving_index) = fixation(&counts) {
+        termination_reason = TerminationReason::Monoculture {
+            surviving_index,
+            step: 0,
+        };
+    }
+    for step in 1..=num_steps {
+        if termination_reason.is_terminal() {
+            break;
+        }
+
+        fitness_inplace(&counts, n, interaction_matrix, &growth_vector, &mut fitness);
+        match finite_population.update {
+            FinitePopulationUpdate::WrightFisher => {
+                wright_fisher_generation(&mut counts, &fitness, population_size, dt, &mut rng);
+            }
+            FinitePopulationUpdate::Moran => {
+                moran_step(
+                    &mut counts,
+                    &mut fitness,
+                    &mut weights,
+                    population_size,
+                    interaction_matrix,
+                    dt,
+                    &mut rng,
+                );
+            }
+        }
+
+        previous.copy_from_slice(gs_curr.state.as_slice().expect("state is contiguous"));
+        write_counts(&mut gs_curr, &counts, population_size);
+        gs_curr.time = start_time + step;
+        gs_curr.physical_time = start_physical_time + step as f64 * dt;
+        steps_run = step;
+        record_extinctions(
+            &previous,
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
+
+        let saved = step % save_interval == 0;
+        if saved {
+            signal_writer.push(&gs_curr)?;
+        }
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        let reason = if let Some(surviving_index) = fixation(&counts) {
+            Some(TerminationReason::Monoculture {
+                surviving_index,
+                step,
+            })
+        } else {
+            termination_checker
+                .as_mut()
+                .and_then(|checker| checker.check(&gs_curr, step))
+        };
+        if let Some(reason) = reason {
+            termination_reason = reason;
+            if !saved {
+                signal_writer.push(&gs_curr)?;
+            }
+            break;
+        }
+    }
+
+    let signal_stats = signal_writer.finish()?;
+
+    Ok(SolveOutcome {
+        final_state: gs_curr,
+        steps_run,
+        reason: termination_reason,
+        signal_stats,
+        space_stats: None,
+        step_stats: None,
+        events: Vec::new(),
+        extinctions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_non_spatial_finite_population_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn apportion_places_exactly_n_individuals() {
+        let mut counts = [0.0; 3];
+        apportion(&[0.335, 0.333, 0.332], 10, &mut counts);
+        assert_eq!(counts.iter().sum::<f64>(), 10.0);
+        assert_eq!(counts, [4.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn neutral_drift_fixes_with_probability_equal_to_initial_frequency() {
+        let interaction_matrix = Array2::zeros((2, 2));
+        let output_path = temp_output_dir("neutral");
+        let runs = 400;
+
+        for finite_population in [
+            FinitePopulation::wright_fisher(20),
+            FinitePopulation::moran(20),
+        ] {
+            let mut first_fixed = 0usize;
+            for k in 0..runs {
+                let gs = SystemState::from_arrays(
+                    Mode::Frequency { cutoff: None },
+                    0,
+                    array![0.25, 0.75],
+                    None,
+                );
+                let outcome = solve_finite_population_with_termination(
+                    gs,
+                    &interaction_matrix,
+                    None,
+                    finite_population,
+                    RngSeed::new(7).replicate(k),
+                    1.0,
+                    100_000,
+                    1_000,
+                    &output_path,
+                    None,
+                    TerminationConfig::disabled(),
+                )
+                .expect("solve succeeds");
+
+                match outcome.reason {
+                    TerminationReason::Monoculture {
+                        surviving_index, ..
+                    } => {
+                        if surviving_index == Some(0) {
+                            first_fixed += 1;
+                        }
+                    }
+                    other => panic!("expected fixation, got {other:?}"),
+                }
+                assert_eq!(outcome.final_state.state.sum(), 1.0);
+            }
+
+            let probability = first_fixed as f64 / runs as f64;
+            assert!(
+                (probability - 0.25).abs() < 0.07,
+                "{:?}: fixation probability {probability}",
+                finite_population.update
+            );
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn strong_selection_fixes_the_fitter_type_in_population_mode() {
+        let interaction_matrix = Array2::zeros((2, 2));
+        let growth_vector = array![1.0, 0.0];
+        let output_path = temp_output_dir("selection");
+
+        for finite_population in [
+            FinitePopulation::wright_fisher(500),
+            FinitePopulation::moran(500),
+        ] {
+            let gs = SystemState::from_arrays(
+                Mode::Population {
+                    cutoff: None,
+                    carrying_capacity: None,
+                },
+                0,
+                array![50.0, 50.0],
+                None,
+            );
+            let outcome = solve_finite_population_with_termination(
+                gs,
+                &interaction_matrix,
+                Some(&growth_vector),
+                finite_population,
+                RngSeed::new(3),
+                0.5,
+                10_000,
+                10,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .expect("solve succeeds");
+
+            assert!(matches!(
+                outcome.reason,
+                TerminationReason::Monoculture {
+                    surviving_index: Some(0),
+                    ..
+                }
+            ));
+            assert_eq!(outcome.final_state.state[0], 500.0);
+            assert_eq!(outcome.final_state.mass, 500.0);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
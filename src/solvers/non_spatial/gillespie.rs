@@ -0,0 +1,638 @@
+/*!
+Well-mixed Gillespie stochastic simulation.
+
+Purpose:
+    This module implements the exact stochastic simulation algorithm (SSA)
+    for integer-count GLV populations. Growth and pairwise interaction terms
+    are mapped to birth and death reactions whose mean-field limit is the
+    deterministic `dn_i/dt = n_i (g_i + Σ_j V_ij n_j)`: species `i` is born
+    at rate `n_i (g_i⁺ + Σ_j V_ij⁺ n_j)` and dies at rate
+    `n_i (g_i⁻ + Σ_j V_ij⁻ n_j)`, with `x⁺ = max(x, 0)` and
+    `x⁻ = max(-x, 0)`. Per-pair reactions that
+    change the same species by the same amount are merged, which leaves the
+    jump process unchanged and keeps one event at O(d) cost.
+
+Evolution contract:
+    One event follows this sequence: exponential waiting time from the total
+    propensity, samples for every save time crossed while waiting, then one
+    `SystemState::increase` or `SystemState::decrease`. Counts that drop below
+    the mode cutoff are zeroed, and births are suppressed while the total is
+    at `carrying_capacity`. Signal samples sit on the physical-time grid in
+    `SsaConfig::save_time_interval`; signal `time` records the number of
+    events fired so far.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2};
+use rand::RngExt;
+use rand_distr::{Distribution, Exp1};
+use serde::{Deserialize, Serialize};
+
+use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::{growth_vector_or_zeros, validate_interactions};
+use crate::solvers::extinctions::ExtinctionRecord;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::{
+    SolveOutcome, StepStats, TerminationChecker, TerminationConfig, TerminationReason,
+};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+
+/// Physical-time horizon and save grid for event-driven solves.
+///
+/// Details:
+/// - Purpose: Groups the end time, sampling grid, and event budget of one
+///   stochastic simulation run.
+/// - Parameters:
+///   - `t_end`: Physical end time.
+///   - `save_time_interval`: Physical time between signal samples.
+///   - `max_events`: Upper bound on fired reactions.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SsaConfig {
+    pub t_end: f64,
+    pub save_time_interval: f64,
+    pub max_events: usize,
+}
+
+impl SsaConfig {
+    pub(crate) fn validate(&self) -> Result<()> {
+        let invalid = |message| Error::new(ErrorKind::InvalidInput, message);
+
+        if !self.t_end.is_finite() || self.t_end < 0.0 {
+            return Err(invalid("t_end must be finite and nonnegative"));
+        }
+        if !self.save_time_interval.is_finite() || self.save_time_interval <= 0.0 {
+            return Err(invalid("save_time_interval must be finite and positive"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Birth and death propensities of the GLV reaction network.
+///
+/// Details:
+/// - Purpose: Splits `g` and `V` into signed parts and caches the pressures
+///   `Σ_j V_ij⁺ n_j` and `Σ_j V_ij⁻ n_j`, so one ±1 change of species `k`
+///   refreshes every propensity with one pass over column `k`.
+/// - Parameters:
+///   - (none): Construct with `GlvReactions::new`.
+pub(crate) struct GlvReactions {
+    growth_plus: Vec<f64>,
+    growth_minus: Vec<f64>,
+    interaction_plus: Array2<f64>,
+    interaction_minus: Array2<f64>,
+    pressure_plus: Vec<f64>,
+    pressure_minus: Vec<f64>,
+    pub(crate) birth: Vec<f64>,
+    pub(crate) death: Vec<f64>,
+}
+
+impl GlvReactions {
+    pub(crate) fn new(interaction_matrix: &Array2<f64>, growth_vector: &Array1<f64>) -> Self {
+        let d = interaction_matrix.nrows();
+        Self {
+            growth_plus: growth_vector.iter().map(|&g| g.max(0.0)).collect(),
+            growth_minus: growth_vector.iter().map(|&g| (-g).max(0.0)).collect(),
+            interaction_plus: interaction_matrix.mapv(|v| v.max(0.0)),
+            interaction_minus: interaction_matrix.mapv(|v| (-v).max(0.0)),
+            pressure_plus: vec![0.0; d],
+            pressure_minus: vec![0.0; d],
+            birth: vec![0.0; d],
+            death: vec![0.0; d],
+        }
+    }
+
+    /// Recompute the pressures from scratch for counts `n`.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn reset(&mut self, n: &[f64]) {
+        let d = n.len();
+        for i in 0..d {
+            let mut plus = 0.0;
+            let mut minus = 0.0;
+            for j in 0..d {
+                plus += self.interaction_plus[(i, j)] * n[j];
+                minus += self.interaction_minus[(i, j)] * n[j];
+            }
+            self.pressure_plus[i] = plus;
+            self.pressure_minus[i] = minus;
+        }
+    }
+
+    /// Account for species `k` changing by `delta` individuals.
+    #[inline]
+    pub(crate) fn shift(&mut self, k: usize, delta: f64) {
+        for i in 0..self.pressure_plus.len() {
+            self.pressure_plus[i] += self.interaction_plus[(i, k)] * delta;
+            self.pressure_minus[i] += self.interaction_minus[(i, k)] * delta;
+        }
+    }
+
+    /// Refresh `birth` and `death` for counts `n` and return the total rate.
+    ///
+    /// Details:
+    /// - Purpose: Evaluates every propensity from the cached pressures.
+    ///   Births are switched off when `births_allowed` is false.
+    #[allow(clippy::needless_range_loop)]
+    #[inline]
+    pub(crate) fn update(&mut self, n: &[f64], births_allowed: bool) -> f64 {
+        let mut total = 0.0;
+        for i in 0..n.len() {
+            let ni = n[i];
+            let birth = if births_allowed && ni > 0.0 {
+                ni * (self.growth_plus[i] + self.pressure_plus[i].max(0.0))
+            } else {
+                0.0
+            };
+            let death = if ni > 0.0 {
+                ni * (self.growth_minus[i] + self.pressure_minus[i].max(0.0))
+            } else {
+                0.0
+            };
+            self.birth[i] = birth;
+            self.death[i] = death;
+            total += birth + death;
+        }
+        total
+    }
+}
+
+/// Whether births are allowed at the current total under `mode`.
+#[inline]
+pub(crate) fn births_allowed(mode: &Mode<f64>, mass: f64) -> bool {
+    match mode {
+        Mode::Population {
+            carrying_capacity: Some(capacity),
+            ..
+        } => mass < *capacity,
+        _ => true,
+    }
+}
+
+/// Population cutoff of `mode`, or zero.
+#[inline]
+pub(crate) fn population_cutoff(mode: &Mode<f64>) -> f64 {
+    match mode {
+        Mode::Population { cutoff, .. } | Mode::Frequency { cutoff } => {
+            cutoff.unwrap_or(0.0).max(0.0)
+        }
+    }
+}
+
+/// Round counts to non-negative integers and refresh cached mass.
+///
+/// Details:
+/// - Purpose: Brings a `Mode::Population` state onto the integer lattice the
+///   discrete solvers work on. Non-finite entries and rounded counts below
+///   the cutoff become zero, so the first event never zeroes a species the
+///   initial state already held below the cutoff.
+pub(crate) fn round_counts(gs: &mut SystemState<f64>) -> Result<()> {
+    if !matches!(gs.mode, Mode::Population { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "discrete population solvers require Mode::Population",
+        ));
+    }
+
+    let cutoff = population_cutoff(&gs.mode);
+    for x in gs.state.iter_mut() {
+        let count = if x.is_finite() && *x > 0.0 {
+            x.round()
+        } else {
+            0.0
+        };
+        *x = if count < cutoff { 0.0 } else { count };
+    }
+    gs.mass = gs.state.sum();
+    Ok(())
+}
+
+/// Fire the reaction selected by `target ∈ [0, total)` on `gs`.
+///
+/// Details:
+/// - Purpose: Walks the birth/death propensities, applies the ±1 change,
+///   zeroes counts that fall below `cutoff`, and returns the species index
+///   and count change (`None` if rounding left no reaction to pick).
+#[inline]
+pub(crate) fn fire_reaction(
+    reactions: &GlvReactions,
+    gs: &mut SystemState<f64>,
+    mut target: f64,
+    cutoff: f64,
+) -> Option<(usize, f64)> {
+    let d = gs.state.len();
+    let mut chosen = None;
+    for i in 0..d {
+        if target < reactions.birth[i] {
+            chosen = Some((i, 1.0));
+            break;
+        }
+        target -= reactions.birth[i];
+        if target < reactions.death[i] {
+            chosen = Some((i, -1.0));
+            break;
+        }
+        target -= reactions.death[i];
+    }
+
+    // Floating-point leftovers select the last reaction with positive rate.
+    let (k, delta) = chosen.or_else(|| {
+        (0..d).rev().find_map(|i| {
+            if reactions.death[i] > 0.0 {
+                Some((i, -1.0))
+            } else if reactions.birth[i] > 0.0 {
+                Some((i, 1.0))
+            } else {
+                None
+            }
+        })
+    })?;
+
+    if delta > 0.0 {
+        gs.increase(k + 1);
+    } else {
+        gs.decrease(k + 1);
+    }
+
+    // Apply the cutoff to the changed species only.
+    let value = gs.state[k];
+    if value > 0.0 && value < cutoff {
+        gs.set(k + 1, 0.0);
+        return Some((k, delta - value));
+    }
+
+    Some((k, delta))
+}
+
+/// Simulate a single well-mixed GLV trajectory with exact SSA.
+///
+/// Details:
+/// - Purpose: Runs one integer-count `Mode::Population` trajectory up to
+///   `config.t_end` with Gillespie's direct method and writes signal samples
+///   on a regular physical-time grid. Initial counts are rounded.
+/// - Parameters:
+///   - `gs_i`: Initial population state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `config`: Time horizon, save grid, and event budget.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter (events).
+///
+/// Draws come from a fresh entropy seed; pass an explicit `RngSeed` to
+/// `solve_glv_ssa_with_termination` for reproducible runs.
+pub fn solve_glv_ssa(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: SsaConfig,                      // time horizon and save grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_glv_ssa_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        config,
+        RngSeed::from_entropy(),
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Simulate a single well-mixed GLV trajectory with exact SSA and explicit termination.
+///
+/// Details:
+/// - Purpose: Same as `solve_glv_ssa`, returning `SolveOutcome` whose
+///   `step_stats` hold the event count and final physical time. Termination
+///   checks run on `check_interval` multiples of the event count. A state
+///   with zero total propensity is absorbing: remaining samples repeat it up
+///   to `t_end`. Waiting times and reaction choices are drawn from `seed`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_ssa_with_termination(
+    mut gs_i: SystemState<f64>,             // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: SsaConfig,                      // time horizon and save grid
+    seed: RngSeed,                          // random stream
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    config.validate()?;
+    round_counts(&mut gs_i)?;
+    validate_interactions(interaction_matrix.dim(), growth_vector, gs_i.state.len())?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let mut reactions = GlvReactions::new(interaction_matrix, &growth_vector);
+    let cutoff = population_cutoff(&gs_i.mode);
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        gs_curr.state.len(),
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let mut rng = seed.rng();
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut extinctions = Vec::new();
+    reactions.reset(gs_curr.state.as_slice().expect("state is contiguous"));
+
+    // Main loop: waiting time -> crossed samples -> fire one reaction.
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut stats = StepStats::default();
+    let mut t = 0.0;
+    let mut save_index = 1usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    while t < config.t_end && stats.accepted_steps < config.max_events {
+        let allowed = births_allowed(&gs_curr.mode, gs_curr.mass);
+        let total = reactions.update(
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            allowed,
+        );
+        let t_next = if total > 0.0 {
+            let wait: f64 = Exp1.sample(&mut rng);
+            t + wait / total
+        } else {
+            f64::INFINITY
+        };
+
+        // The state is constant until t_next: save every grid time crossed.
+        save_index = push_samples_until(
+            &mut signal_writer,
+            &gs_curr,
+            &config,
+            start_physical_time,
+            save_index,
+            t_next,
+        )?;
+        if t_next >= config.t_end {
+            t = config.t_end;
+            break;
+        }
+
+        t = t_next;
+        let target = rng.random::<f64>() * total;
+        let fired = fire_reaction(&reactions, &mut gs_curr, target, cutoff);
+        if let Some((k, delta)) = fired {
+            reactions.shift(k, delta);
+        }
+        stats.accepted_steps += 1;
+        gs_curr.time = start_time + stats.accepted_steps;
+        gs_curr.physical_time = start_physical_time + t;
+        let step = stats.accepted_steps;
+
+        // The fired species held `-delta` before it dropped to zero.
+        if let Some((k, delta)) = fired
+            && delta < 0.0
+            && gs_curr.state[k] == 0.0
+        {
+            extinctions.push(ExtinctionRecord {
+                step,
+                time: gs_curr.physical_time,
+                species: k,
+                abundance: -delta,
+            });
+        }
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        if let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            signal_writer.push(&gs_curr)?;
+            break;
+        }
+    }
+    gs_curr.physical_time = start_physical_time + t;
+    stats.final_time = gs_curr.physical_time;
+
+    let signal_stats = signal_writer.finish()?;
+
+    Ok(SolveOutcome {
+        final_state: gs_curr,
+        steps_run: stats.accepted_steps,
+        reason: termination_reason,
+        signal_stats,
+        space_stats: None,
+        step_stats: Some(stats),
+        events: Vec::new(),
+        extinctions,
+    })
+}
+
+/// Push `gs` for every save time `<= t_until` and return the next save index.
+///
+/// Details:
+/// - Purpose: Samples a piecewise-constant trajectory on the grid
+///   `min(k * save_time_interval, t_end)`; the final partial interval is
+///   sampled once at `t_end`. Samples are stamped with physical time
+///   `start_time + t_save`.
+pub(crate) fn push_samples_until(
+    signal_writer: &mut SignalWriter,
+    gs: &SystemState<f64>,
+    config: &SsaConfig,
+    start_time: f64,
+    mut save_index: usize,
+    t_until: f64,
+) -> Result<usize> {
+    loop {
+        let t_grid = save_index as f64 * config.save_time_interval;
+        let t_save = t_grid.min(config.t_end);
+        let past_end = (save_index - 1) as f64 * config.save_time_interval >= config.t_end;
+        if past_end || t_save > t_until {
+            return Ok(save_index);
+        }
+        signal_writer.push_at(gs, start_time + t_save)?;
+        save_index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::signal::load_signal_series;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_non_spatial_gillespie_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    fn population(values: Array1<f64>) -> SystemState<f64> {
+        SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            values,
+            None,
+        )
+    }
+
+    #[test]
+    fn propensities_reproduce_the_glv_drift() {
+        let v = array![[-0.1, 0.2], [-0.3, -0.05]];
+        let g = array![1.0, -0.5];
+        let n = [7.0, 4.0];
+        let mut reactions = GlvReactions::new(&v, &g);
+        reactions.reset(&n);
+        reactions.update(&n, true);
+
+        for i in 0..2 {
+            let drift = n[i] * (g[i] + v[(i, 0)] * n[0] + v[(i, 1)] * n[1]);
+            assert!((reactions.birth[i] - reactions.death[i] - drift).abs() < 1e-12);
+        }
+
+        // Incremental pressures match a full reset.
+        reactions.shift(1, -1.0);
+        let after = [7.0, 3.0];
+        reactions.update(&after, true);
+        let (birth, death) = (reactions.birth.clone(), reactions.death.clone());
+        reactions.reset(&after);
+        reactions.update(&after, true);
+        for i in 0..2 {
+            assert!((birth[i] - reactions.birth[i]).abs() < 1e-12);
+            assert!((death[i] - reactions.death[i]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn pure_death_reaches_extinction_on_the_save_grid() {
+        let v = Array2::zeros((1, 1));
+        let g = array![-1.0];
+        let output_path = temp_output_dir("pure_death");
+        let config = SsaConfig {
+            t_end: 50.0,
+            save_time_interval: 5.0,
+            max_events: 1_000,
+        };
+
+        // A resumed run: grid samples are stamped from the initial physical time.
+        let mut gs = population(array![20.4]);
+        gs.physical_time = 100.0;
+        let outcome = solve_glv_ssa_with_termination(
+            gs,
+            &v,
+            Some(&g),
+            config,
+            RngSeed::new(1),
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        // Each of the 20 rounded individuals dies exactly once.
+        let stats = outcome.step_stats.expect("ssa stats");
+        assert_eq!(outcome.final_state.state[0], 0.0);
+        assert_eq!(outcome.steps_run, 20);
+        assert_eq!(stats.final_time, 150.0);
+        assert_eq!(outcome.signal_stats.samples, 11);
+        assert_eq!(outcome.final_state.physical_time, 150.0);
+        let series = load_signal_series(&output_path.join("signal/1.json")).expect("signal loads");
+        let times: Vec<_> = series.samples.iter().map(|s| s.physical_time).collect();
+        let grid: Vec<_> = (0..=10).map(|k| Some(100.0 + 5.0 * k as f64)).collect();
+        assert_eq!(times, grid);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn logistic_births_stop_at_carrying_capacity_and_reject_frequency_mode() {
+        let v = Array2::zeros((2, 2));
+        let g = array![1.0, 1.0];
+        let output_path = temp_output_dir("capacity");
+        let config = SsaConfig {
+            t_end: 20.0,
+            save_time_interval: 1.0,
+            max_events: 10_000,
+        };
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: Some(50.0),
+            },
+            0,
+            array![2.0, 3.0],
+            None,
+        );
+
+        let out =
+            solve_glv_ssa(gs, &v, Some(&g), config, &output_path, None).expect("solve succeeds");
+        assert_eq!(out.state.sum(), 50.0);
+        assert!(out.state.iter().all(|x| x.fract() == 0.0));
+
+        let frequency =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let err = solve_glv_ssa(frequency, &v, Some(&g), config, &output_path, None)
+            .err()
+            .expect("frequency mode rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn initial_counts_below_the_cutoff_start_extinct_and_shapes_are_checked() {
+        let v = Array2::zeros((2, 2));
+        let g = array![1.0, 0.0];
+        let output_path = temp_output_dir("initial_cutoff");
+        let config = SsaConfig {
+            t_end: 1.0,
+            save_time_interval: 1.0,
+            max_events: 10,
+        };
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: Some(5.0),
+                carrying_capacity: None,
+            },
+            0,
+            array![3.0, 10.0],
+            None,
+        );
+
+        let outcome = solve_glv_ssa_with_termination(
+            gs.clone(),
+            &v,
+            Some(&g),
+            config,
+            RngSeed::new(3),
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+        let series = load_signal_series(&output_path.join("signal/1.json")).expect("signal loads");
+        assert_eq!(series.samples[0].state[0], 0.0);
+        assert_eq!(outcome.final_state.state[0], 0.0);
+        assert!(outcome.extinctions.is_empty());
+
+        for (v, g) in [(Array2::zeros((3, 3)), g.clone()), (v, array![1.0])] {
+            let err = solve_glv_ssa(gs.clone(), &v, Some(&g), config, &output_path, None)
+                .err()
+                .expect("shape mismatch rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
@@ -2,9 +2,19 @@
 Non-spatial solver modules.
 
 Purpose:
-    This module groups well-mixed dynamics: deterministic RK4 integration and
-    optional post-step stochastic updates.
+    This module groups well-mixed dynamics: fixed-step integration through the
+    shared integrators, adaptive RK45 integration, optional post-step
+    stochastic updates, SDE schemes that integrate drift and noise
+    together, exact Gillespie simulation and tau-leaping of integer counts,
+    Wright–Fisher/Moran finite-population replicator updates, and the
+    MacArthur consumer–resource model with explicit resources.
 */
 
+pub mod consumer_resource;
+pub mod finite_population;
+pub mod gillespie;
 pub mod noise;
 pub mod rk4;
+pub mod rk45;
+pub mod sde;
+pub mod tau_leaping;
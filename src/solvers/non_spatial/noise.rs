@@ -2,22 +2,33 @@
 Non-spatial stochastic updates.
 
 Purpose:
-    This module defines optional stochasticity applied after a deterministic
-    solver step. Noise mutates `SystemState<f64>` in place and finishes by
-    calling `SystemState::sanitize`.
+    This module defines optional stochasticity for the well-mixed solvers.
+    With the default `SdeScheme::PostStepSplitting`, noise is applied after a
+    deterministic solver step, mutates `SystemState<f64>` in place, and
+    finishes by calling `SystemState::sanitize`. Other schemes integrate the
+    same kinds as SDEs in `sde`.
 
 Runtime model:
     `Noise` is the user-facing configuration. `NoiseContext` owns reusable
     buffers and distribution objects so hot solver loops do not allocate random
     scratch space every step.
+
+Noise families:
+    Simplex-centred kinds subtract a weighted mean draw so updates stay close
+    to the simplex and suit `Mode::Frequency`. Population kinds perturb
+    absolute counts independently and suit `Mode::Population`.
 */
 #![allow(dead_code)]
 
-use crate::SystemState;
+use std::io::{Error, ErrorKind, Result};
+
+use super::sde::{Calculus, SdeScheme};
+use crate::{Mode, SystemState};
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub enum NoiseKind {
     /// No noise.
     None,
@@ -33,34 +44,133 @@ pub enum NoiseKind {
     ///     ν_i < ν_i + σ sqrt(ν_i) (η_i - \bar{η}_{sqrt(ν)}) sqrt(dt)
     ///     where \bar{η}_{sqrt(ν)} = (Σ_j sqrt(ν_j) η_j) / (Σ_j sqrt(ν_j)).
     DemographicGaussian { sigma: f64 },
+
+    /// Population demographic noise:
+    ///     Independent Gaussian fluctuations proportional to sqrt(n_i).
+    ///     n_i < n_i + σ sqrt(n_i) η_i sqrt(dt)
+    PopulationDemographicGaussian { sigma: f64 },
+
+    /// Environmental noise on absolute counts:
+    ///     n_i < n_i [1 + σ η_i sqrt(dt)]
+    ///     without simplex centring.
+    EnvironmentalGaussian { sigma: f64 },
+}
+
+impl NoiseKind {
+    /// Whether this kind subtracts a simplex-weighted mean draw.
+    #[inline]
+    pub fn is_simplex_centred(&self) -> bool {
+        matches!(
+            self,
+            Self::ProportionalGaussian { .. } | Self::DemographicGaussian { .. }
+        )
+    }
 }
 
 /// Noise configuration wrapper (public API).
-#[derive(Clone, Copy, Debug)]
+///
+/// Details:
+/// - Purpose: Pairs a noise kind with the scheme that integrates it and the
+///   calculus used to read it. Constructors default to
+///   `SdeScheme::PostStepSplitting` with `Calculus::Ito`.
+/// - Parameters:
+///   - `kind`: Diffusion term.
+///   - `scheme`: Post-step splitting or an SDE scheme.
+///   - `calculus`: Itô or Stratonovich interpretation.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct Noise {
     pub kind: NoiseKind,
+    pub scheme: SdeScheme,
+    pub calculus: Calculus,
 }
 
 impl Noise {
     #[inline]
-    pub fn none() -> Self {
+    fn from_kind(kind: NoiseKind) -> Self {
         Self {
-            kind: NoiseKind::None,
+            kind,
+            scheme: SdeScheme::default(),
+            calculus: Calculus::default(),
         }
     }
 
+    #[inline]
+    pub fn none() -> Self {
+        Self::from_kind(NoiseKind::None)
+    }
+
     #[inline]
     pub fn proportional_gaussian(sigma: f64) -> Self {
-        Self {
-            kind: NoiseKind::ProportionalGaussian { sigma },
-        }
+        Self::from_kind(NoiseKind::ProportionalGaussian { sigma })
     }
 
     #[inline]
     pub fn demographic_gaussian(sigma: f64) -> Self {
-        Self {
-            kind: NoiseKind::DemographicGaussian { sigma },
+        Self::from_kind(NoiseKind::DemographicGaussian { sigma })
+    }
+
+    #[inline]
+    pub fn population_demographic_gaussian(sigma: f64) -> Self {
+        Self::from_kind(NoiseKind::PopulationDemographicGaussian { sigma })
+    }
+
+    #[inline]
+    pub fn environmental_gaussian(sigma: f64) -> Self {
+        Self::from_kind(NoiseKind::EnvironmentalGaussian { sigma })
+    }
+
+    #[inline]
+    pub fn with_scheme(mut self, scheme: SdeScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    #[inline]
+    pub fn with_calculus(mut self, calculus: Calculus) -> Self {
+        self.calculus = calculus;
+        self
+    }
+
+    /// Whether the solver replaces its integrator step with an SDE step.
+    #[inline]
+    pub fn uses_sde_scheme(&self) -> bool {
+        !matches!(self.kind, NoiseKind::None) && self.scheme != SdeScheme::PostStepSplitting
+    }
+
+    /// Reject scheme/kind/calculus combinations without a defined update.
+    ///
+    /// Details:
+    /// - Purpose: Post-step splitting is an Itô update, and Milstein is only
+    ///   offered for diagonal (uncentred) kinds.
+    pub fn validate(&self) -> Result<()> {
+        if matches!(self.kind, NoiseKind::None) {
+            return Ok(());
+        }
+        if self.scheme == SdeScheme::PostStepSplitting && self.calculus == Calculus::Stratonovich {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "post-step splitting is Itô only; choose an SDE scheme for Stratonovich noise",
+            ));
         }
+        if self.scheme == SdeScheme::Milstein && self.kind.is_simplex_centred() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Milstein needs diagonal noise; use EulerMaruyama or StochasticHeun for simplex-centred kinds",
+            ));
+        }
+        Ok(())
+    }
+
+    /// `validate`, and reject simplex-centred kinds for population states.
+    pub fn validate_for(&self, mode: &Mode<f64>) -> Result<()> {
+        self.validate()?;
+        if matches!(mode, Mode::Population { .. }) && self.kind.is_simplex_centred() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Mode::Population requires a population noise kind",
+            ));
+        }
+        Ok(())
     }
 }
 
@@ -228,5 +338,102 @@ pub fn apply_noise_inplace(
             // ----------------------------------------------------------------------------------
             state.sanitize();
         }
+
+        NoiseKind::PopulationDemographicGaussian { sigma } => {
+            if sigma == 0.0 {
+                return;
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (1) Sample eta_i ~ N(0,1)
+            // ----------------------------------------------------------------------------------
+            for e in ctx.eta.iter_mut() {
+                *e = ctx.normal.sample(rng_local);
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (2) Additive uncentred update
+            // ----------------------------------------------------------------------------------
+            let scale = sigma * dt.sqrt();
+            let n_mut = &mut state.state;
+            for i in 0..d {
+                let xi = if n_mut[i] > 0.0 { n_mut[i] } else { 0.0 };
+                let val = xi + scale * xi.sqrt() * ctx.eta[i];
+                n_mut[i] = if val.is_finite() && val > 0.0 {
+                    val
+                } else {
+                    0.0
+                };
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (3) Restore population constraints
+            // ----------------------------------------------------------------------------------
+            state.sanitize();
+        }
+
+        NoiseKind::EnvironmentalGaussian { sigma } => {
+            if sigma == 0.0 {
+                return;
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (1) Sample eta_i ~ N(0,1)
+            // ----------------------------------------------------------------------------------
+            for e in ctx.eta.iter_mut() {
+                *e = ctx.normal.sample(rng_local);
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (2) Multiplicative uncentred update
+            // ----------------------------------------------------------------------------------
+            let scale = sigma * dt.sqrt();
+            let n_mut = &mut state.state;
+            for i in 0..d {
+                let val = n_mut[i] * (1.0 + scale * ctx.eta[i]);
+                n_mut[i] = if val.is_finite() && val > 0.0 {
+                    val
+                } else {
+                    0.0
+                };
+            }
+
+            // ----------------------------------------------------------------------------------
+            // (3) Restore population constraints
+            // ----------------------------------------------------------------------------------
+            state.sanitize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mode;
+    use ndarray::array;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    #[test]
+    fn population_noise_is_uncentred_and_keeps_extinct_species_at_zero() {
+        let mode = Mode::Population {
+            cutoff: None,
+            carrying_capacity: None,
+        };
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut ctx = NoiseContext::new(3);
+
+        for noise in [
+            Noise::population_demographic_gaussian(0.5),
+            Noise::environmental_gaussian(0.5),
+        ] {
+            let mut gs = SystemState::from_arrays(mode.clone(), 0, array![10.0, 0.0, 5.0], None);
+            apply_noise_inplace(&mut gs, noise, 0.1, &mut ctx, &mut rng);
+
+            assert!(!noise.kind.is_simplex_centred());
+            assert_eq!(gs.state[1], 0.0);
+            assert!(gs.state[0] > 0.0 && gs.state[2] > 0.0);
+            assert_ne!(gs.state.sum(), 15.0);
+        }
     }
 }
@@ -1,267 +1,940 @@
 /*!
-Well-mixed replicator RK4 solver.
+Well-mixed RK4 solver.
 
 Purpose:
-    This module implements the active trajectory solver. It computes the
-    replicator right-hand side, advances the state with RK4, restores state
-    invariants, applies optional noise, and persists aggregate signal snapshots.
+    This module implements the active well-mixed trajectory solvers. It
+    evaluates a `Dynamics` reaction term (built-in replicator/GLV or a user
+    model), advances the state with a pluggable `Integrator` (RK4 by default),
+    restores state invariants, applies optional noise, and persists aggregate
+    signal snapshots.
 
 Evolution contract:
-    One solver step follows this sequence: RK4 raw step,
-    `SystemState::sanitize`, optional noise, then snapshot check.
+    One solver step follows this sequence: integrator raw step,
+    `SystemState::sanitize`, optional noise, then snapshot check. When the
+    noise selects an SDE scheme, the first three stages collapse into one
+    `sde_step_inplace` followed by `SystemState::sanitize`; the integrator is
+    not used for that run.
 */
 
-use std::io::Result;
+use std::io::{Error, ErrorKind, Result};
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use ndarray::{Array1, Array2};
 
-use rand::SeedableRng;
-use rand::rngs::SmallRng;
-
 use super::noise::{Noise, NoiseContext, apply_noise_inplace};
+use super::sde::{SdeContext, sde_step_inplace};
 use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::{
+    Dynamics, GlvPopulation, Immigration, Replicator, ReplicatorMutator, growth_vector_or_zeros,
+    validate_immigration, validate_interactions, validate_mutation_matrix,
+};
+use crate::solvers::events::{EventQueue, EventRecord, EventSchedule, introduce_inplace};
+use crate::solvers::extinctions::record_extinctions;
+use crate::solvers::forcing::{Forced, Forcing};
+use crate::solvers::functional_response::{FunctionalResponse, HollingGlv};
+use crate::solvers::higher_order::{HigherOrder, InteractionTensor};
+use crate::solvers::integrator::{Integrator, Rk4, Scheme, VectorField};
+use crate::solvers::log_space::{
+    LogSpaceField, from_log_inplace, normalize_log_inplace, sanitize_without_cutoff, to_log_inplace,
+};
+use crate::solvers::rng::RngSeed;
+use crate::solvers::sparse::{CsrMatrix, SparseInteractions};
 use crate::solvers::termination::{
     SolveOutcome, TerminationChecker, TerminationConfig, TerminationReason,
 };
-use crate::{SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
 
-/// Scratch buffers for RK4 (avoid repeated allocations).
+/// Well-mixed vector field for the shared integrators.
 ///
 /// Details:
-/// - Purpose: Owns stage derivatives and matrix-vector scratch storage for the
-///   hot integration loop.
+/// - Purpose: Exposes a `Dynamics` reaction term (and its Jacobian, when
+///   provided) as the `VectorField` integrated by the step loop.
 /// - Parameters:
-///   - (none): Construct with `Rk4Scratch::new`.
-struct Rk4Scratch {
-    k1: Array1<f64>,    // stage 1 derivative
-    k2: Array1<f64>,    // stage 2 derivative
-    k3: Array1<f64>,    // stage 3 derivative
-    k4: Array1<f64>,    // stage 4 derivative
-    tmp: Array1<f64>,   // intermediate ν
-    w: Array1<f64>,     // w = Vν
-    drift: Array1<f64>, // drift = g + w - Υ
-}
+///   - `0`: Reaction term of the whole well-mixed community.
+///   - `1`: Step start time, used by integrators that call `eval` rather
+///     than `eval_at`.
+pub(crate) struct WellMixedField<'a>(pub(crate) &'a dyn Dynamics, pub(crate) f64);
 
-impl Rk4Scratch {
+impl VectorField for WellMixedField<'_> {
     #[inline]
-    fn new(d: usize) -> Self {
-        Self {
-            k1: Array1::zeros(d),
-            k2: Array1::zeros(d),
-            k3: Array1::zeros(d),
-            k4: Array1::zeros(d),
-            tmp: Array1::zeros(d),
-            w: Array1::zeros(d),
-            drift: Array1::zeros(d),
-        }
+    fn eval(&mut self, u: &[f64], out: &mut [f64]) -> Result<()> {
+        self.eval_at(self.1, u, out)
     }
+
+    #[inline]
+    fn eval_at(&mut self, t: f64, u: &[f64], out: &mut [f64]) -> Result<()> {
+        self.0.reaction_at(t, u, out);
+        Ok(())
+    }
+
+    #[inline]
+    fn jacobian(&mut self, u: &[f64], out: &mut Array2<f64>) -> Result<bool> {
+        self.jacobian_at(self.1, u, out)
+    }
+
+    #[inline]
+    fn jacobian_at(&mut self, t: f64, u: &[f64], out: &mut Array2<f64>) -> Result<bool> {
+        Ok(self.0.jacobian_at(t, u, out))
+    }
+}
+
+/// Integrate a single replicator trajectory and persist aggregate signal output.
+///
+/// Details:
+/// - Purpose: Runs one trajectory and writes signal snapshots to disk.
+/// - Parameters:
+///   - `gs_i`: Initial state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `noise`: Optional post-step stochastic update.
+///   - `dt`: Step size.
+///   - `num_steps`: Number of integration steps.
+///   - `save_interval`: Save every Nth step; `t = 0` is always saved.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter.
+///
+/// Noise draws come from a fresh entropy seed; use `solve_with_termination`
+/// with an explicit `RngSeed` for reproducible runs.
+#[allow(clippy::too_many_arguments)]
+pub fn solve(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        RngSeed::from_entropy(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single replicator trajectory with explicit termination configuration.
+///
+/// Details:
+/// - Purpose: Same as `solve`, but noise draws come from `seed`, so equal
+///   seeds replay the trajectory bit-for-bit.
+/// - Parameters:
+///   - `seed`: Key and stream of the noise generator.
+///   - `termination`: Explicit termination behavior.
+///   - (others): As in `solve`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_termination(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        seed,
+        &mut Rk4::default(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single replicator trajectory with an explicit step scheme.
+///
+/// Details:
+/// - Purpose: Same as `solve_with_termination`, but lets callers pick the
+///   built-in scheme, e.g. `Scheme::Rosenbrock` for stiff interaction
+///   matrices.
+/// - Parameters:
+///   - `scheme`: Fixed-step integration scheme.
+///   - (others): As in `solve_with_termination`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_scheme(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    scheme: Scheme,                         // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        seed,
+        scheme.integrator().as_mut(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single replicator trajectory with a caller-supplied integrator.
+///
+/// Details:
+/// - Purpose: Same as `solve_with_termination`, but steps with any
+///   `Integrator`, including user implementations. The integrator sees the
+///   replicator field and its analytic Jacobian.
+/// - Parameters:
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve_with_termination`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_integrator(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = Replicator {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single replicator–mutator trajectory and persist aggregate signal output.
+///
+/// Details:
+/// - Purpose: Runs one trajectory of
+///   `dν_i/dt = Σ_j Q_ji ν_j f_j - Υ ν_i` with `f = g + Vν` over a
+///   `Mode::Frequency` state.
+/// - Parameters:
+///   - `mutation_matrix`: Row-stochastic mutation matrix `Q`.
+///   - (others): As in `solve`.
+///
+/// Noise draws come from a fresh entropy seed; use
+/// `solve_replicator_mutator_with_termination` with an explicit `RngSeed`
+/// for reproducible runs.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    mutation_matrix: &Array2<f64>,          // Q
+    noise: Noise,                           // noise model
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_replicator_mutator_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        mutation_matrix,
+        noise,
+        RngSeed::from_entropy(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single replicator–mutator trajectory with explicit termination configuration.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator_with_termination(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    mutation_matrix: &Array2<f64>,          // Q
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_replicator_mutator_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        mutation_matrix,
+        noise,
+        seed,
+        &mut Rk4::default(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single replicator–mutator trajectory with a caller-supplied integrator.
+///
+/// Details:
+/// - Purpose: Same as `solve_replicator_mutator_with_termination`, but steps
+///   with any `Integrator`. The integrator sees the replicator–mutator field
+///   and its analytic Jacobian.
+/// - Parameters:
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve_replicator_mutator_with_termination`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator_with_integrator(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    mutation_matrix: &Array2<f64>,          // Q
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    require_mode(&gs_i.mode, true, "replicator-mutator")?;
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    validate_mutation_matrix(mutation_matrix, d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = ReplicatorMutator {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        mutation_matrix,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single well-mixed GLV trajectory and persist aggregate signal output.
+///
+/// Details:
+/// - Purpose: Runs one trajectory of `dn_i/dt = n_i (g_i + Σ_j V_ij n_j)`
+///   over a `Mode::Population` state. Cutoff and carrying capacity are
+///   enforced by `SystemState::sanitize` after every step.
+/// - Parameters:
+///   - `gs_i`: Initial population state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `noise`: Optional post-step population noise; simplex-centred kinds
+///     are rejected.
+///   - `dt`: Step size.
+///   - `num_steps`: Number of integration steps.
+///   - `save_interval`: Save every Nth step; `t = 0` is always saved.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter.
+///
+/// Noise draws come from a fresh entropy seed; use
+/// `solve_glv_with_termination` with an explicit `RngSeed` for reproducible
+/// runs.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_glv_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        RngSeed::from_entropy(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single well-mixed GLV trajectory with explicit termination configuration.
+///
+/// Details:
+/// - Purpose: Same as `solve_glv`, but noise draws come from `seed`, so equal
+///   seeds replay the trajectory bit-for-bit.
+/// - Parameters:
+///   - `seed`: Key and stream of the noise generator.
+///   - `termination`: Explicit termination behavior.
+///   - (others): As in `solve_glv`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_with_termination(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_glv_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        seed,
+        &mut Rk4::default(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single well-mixed GLV trajectory with an explicit step scheme.
+///
+/// Details:
+/// - Purpose: Same as `solve_glv_with_termination`, but lets callers pick the
+///   built-in scheme, e.g. `Scheme::Rosenbrock` for strongly
+///   self-limited `V_ii`.
+/// - Parameters:
+///   - `scheme`: Fixed-step integration scheme.
+///   - (others): As in `solve_glv_with_termination`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_with_scheme(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    scheme: Scheme,                         // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_glv_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        noise,
+        seed,
+        scheme.integrator().as_mut(),
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single well-mixed GLV trajectory with a caller-supplied integrator.
+///
+/// Details:
+/// - Purpose: Same as `solve_glv_with_termination`, but steps with any
+///   `Integrator`, including user implementations. The integrator sees the
+///   GLV field and its analytic Jacobian.
+/// - Parameters:
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve_glv_with_termination`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_with_integrator(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    require_mode(&gs_i.mode, false, "well-mixed GLV")?;
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = GlvPopulation {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single GLV trajectory with saturating functional responses.
+///
+/// Details:
+/// - Purpose: Runs `HollingGlv`, where each cross-species term `V_ij n_j`
+///   saturates in the resource's density: `V_ij φ(n_j; h_ij)` for a
+///   consumer (`V_ij >= 0`) and `V_ij n_j φ(n_i; h_ji) / n_i` for a resource
+///   (`V_ij < 0`). Requires `Mode::Population`.
+/// - Parameters:
+///   - `response`: Holling type and per-pair handling times `h` (`d×d`,
+///     nonnegative).
+///   - (others): As in `solve_glv_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_with_functional_response(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    response: &FunctionalResponse,          // Holling type and h
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    require_mode(&gs_i.mode, false, "well-mixed GLV")?;
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    response.validate(d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = HollingGlv {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        response,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single open-community trajectory with per-species immigration.
+///
+/// Details:
+/// - Purpose: Adds the immigration source `λ_i` to the built-in reaction term
+///   selected by `gs_i.mode`: GLV `f_i(n) + λ_i` for `Mode::Population`, and
+///   the simplex-centred replicator `f_i(ν) + λ_i - ν_i Σ_j λ_j` for
+///   `Mode::Frequency`. Extinct species re-enter at rate `λ_i`.
+/// - Parameters:
+///   - `immigration`: Per-species immigration rates `λ` (nonnegative). Each
+///     positive rate must satisfy `λ_i dt >= cutoff`, otherwise the post-step
+///     cutoff would erase the inflow.
+///   - (others): As in `solve_with_integrator` and `solve_glv_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_immigration(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    immigration: &Array1<f64>,              // λ
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    let cutoff = match gs_i.mode {
+        Mode::Frequency { cutoff } | Mode::Population { cutoff, .. } => cutoff,
+    };
+    validate_immigration(immigration, d, cutoff, dt)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics: Box<dyn Dynamics> = match gs_i.mode {
+        Mode::Frequency { .. } => Box::new(Immigration::frequency(
+            Replicator {
+                interaction_matrix,
+                growth_vector: &growth_vector,
+            },
+            immigration,
+        )),
+        Mode::Population { .. } => Box::new(Immigration::population(
+            GlvPopulation {
+                interaction_matrix,
+                growth_vector: &growth_vector,
+            },
+            immigration,
+        )),
+    };
+
+    solve_dynamics(
+        gs_i,
+        dynamics.as_ref(),
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single trajectory under time-dependent `g` and `V`.
+///
+/// Details:
+/// - Purpose: Runs the built-in reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, replicator for `Mode::Frequency`) with `forcing`
+///   applied at the state's `physical_time`. Integrator stages and SDE
+///   drift evaluations see their own stage times.
+/// - Parameters:
+///   - `forcing`: Seasonal or piecewise-constant forcing of `g` and `V`.
+///   - (others): As in `solve_with_integrator` and `solve_glv_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_forced(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // base V
+    growth_vector: Option<&Array1<f64>>,    // base g override
+    forcing: &Forcing,                      // g(t), V(t)
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    forcing.validate(d)?;
+    let frequency = matches!(gs_i.mode, Mode::Frequency { .. });
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = Forced {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        forcing,
+        frequency,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
 }
 
-/// Compute the RHS in-place:
-///     out = rhs(ν) = ν ⊙ ( g + Vν - Υ ),
-///     where Υ = Σ_i ν_i ( g_i + (Vν)_i ).
+/// Integrate a single trajectory with a sparse interaction matrix.
 ///
 /// Details:
-/// - Purpose: Evaluates the replicator vector field without allocating.
+/// - Purpose: Runs the built-in reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, replicator for `Mode::Frequency`) with `V` in CSR
+///   form, so each evaluation costs `O(nnz + d)` instead of `O(d²)`. Results
+///   match `solve_with_integrator` and `solve_glv_with_integrator` on the
+///   dense copy of `V`.
 /// - Parameters:
-///   - `nu`: Current state.
-///   - `growth_vector`: Growth vector `g`.
-///   - `interaction_matrix`: Interaction matrix `V`.
-///   - `w`: Scratch for `V nu`.
-///   - `drift`: Scratch for centered drift.
-///   - `out`: Destination derivative.
-#[inline]
-fn rhs_inplace(
-    nu: &Array1<f64>,                 // current state ν (len d)
-    growth_vector: &Array1<f64>,      // g (len d)
-    interaction_matrix: &Array2<f64>, // V (d×d)
-    w: &mut Array1<f64>,              // scratch: w = Vν
-    drift: &mut Array1<f64>,          // scratch: drift = g + w - Υ
-    out: &mut Array1<f64>,            // output: rhs(ν)
-) {
-    let d = nu.len();
-
-    // (1) w = V · ν
-    for i in 0..d {
-        let mut acc = 0.0;
-        for j in 0..d {
-            acc += interaction_matrix[(i, j)] * nu[j];
-        }
-        w[i] = acc;
-    }
-
-    // (2) Υ = Σ_i ν_i (g_i + w_i)
-    let mut upsilon = 0.0;
-    for i in 0..d {
-        upsilon += nu[i] * (growth_vector[i] + w[i]);
-    }
+///   - `interaction_matrix`: Square CSR interaction matrix `V`.
+///   - (others): As in `solve_with_integrator` and `solve_glv_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_sparse(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &CsrMatrix,         // sparse V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    let frequency = matches!(gs_i.mode, Mode::Frequency { .. });
 
-    // (3) drift = g + w - Υ
-    for i in 0..d {
-        drift[i] = growth_vector[i] + w[i] - upsilon;
-    }
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = SparseInteractions {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        frequency,
+    };
 
-    // (4) out = ν ⊙ drift
-    for i in 0..d {
-        out[i] = nu[i] * drift[i];
-    }
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
 }
 
-/// One explicit RK4 step (deterministic) writing into `out`.
+/// Integrate a single trajectory with third-order interactions.
 ///
 /// Details:
-/// - Purpose: Advances one raw deterministic step without enforcing
-///   mode-specific state invariants.
+/// - Purpose: Runs the built-in reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, replicator for `Mode::Frequency`) with fitness
+///   `g_i + (Vu)_i + Σ_jk B_ijk u_j u_k`; the replicator mean fitness `Υ`
+///   includes the tensor term.
 /// - Parameters:
-///   - `nu`: Current state.
-///   - `growth_vector`: Growth vector `g`.
-///   - `interaction_matrix`: Interaction matrix `V`.
-///   - `dt`: Step size.
-///   - `sc`: Reusable RK4 scratch storage.
-///   - `out`: Raw next-state destination.
-#[inline]
-fn rk4_step_inplace_raw(
-    nu: &Array1<f64>,                 // current ν
-    growth_vector: &Array1<f64>,      // g
-    interaction_matrix: &Array2<f64>, // V
-    dt: f64,                          // step size
-    sc: &mut Rk4Scratch,              // scratch buffers
-    out: &mut Array1<f64>,            // ν_next (raw)
-) {
-    let d = nu.len();
-    let half_dt = 0.5 * dt;
-    let dt_over_6 = dt / 6.0;
-
-    // k1 = rhs(ν)
-    rhs_inplace(
-        nu,
-        growth_vector,
-        interaction_matrix,
-        &mut sc.w,
-        &mut sc.drift,
-        &mut sc.k1,
-    );
-
-    // tmp = ν + 0.5*dt*k1
-    for i in 0..d {
-        sc.tmp[i] = nu[i] + half_dt * sc.k1[i];
-    }
-    // k2 = rhs(tmp)
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
-        interaction_matrix,
-        &mut sc.w,
-        &mut sc.drift,
-        &mut sc.k2,
-    );
+///   - `tensor`: Dense or sparse third-order interaction tensor `B`, sized to
+///     the species axis.
+///   - (others): As in `solve_with_integrator` and `solve_glv_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_higher_order(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    tensor: &InteractionTensor,             // B
+    noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+    tensor.validate(d)?;
+    let frequency = matches!(gs_i.mode, Mode::Frequency { .. });
 
-    // tmp = ν + 0.5*dt*k2
-    for i in 0..d {
-        sc.tmp[i] = nu[i] + half_dt * sc.k2[i];
-    }
-    // k3 = rhs(tmp)
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = HigherOrder {
         interaction_matrix,
-        &mut sc.w,
-        &mut sc.drift,
-        &mut sc.k3,
-    );
+        growth_vector: &growth_vector,
+        tensor,
+        frequency,
+    };
 
-    // tmp = ν + dt*k3
-    for i in 0..d {
-        sc.tmp[i] = nu[i] + dt * sc.k3[i];
-    }
-    // k4 = rhs(tmp)
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
-        interaction_matrix,
-        &mut sc.w,
-        &mut sc.drift,
-        &mut sc.k4,
-    );
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        noise,
+        seed,
+        integrator,
+        dt,
+        num_steps,
+        save_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
 
-    // out = ν + dt/6*(k1 + 2k2 + 2k3 + k4)
-    for i in 0..d {
-        let incr = dt_over_6 * (sc.k1[i] + 2.0 * sc.k2[i] + 2.0 * sc.k3[i] + sc.k4[i]);
-        let mut val = nu[i] + incr;
-        if !val.is_finite() || val <= 0.0 {
-            val = 0.0;
-        }
-        out[i] = val;
+/// Reject `mode` unless it is `Mode::Frequency` (`frequency`) or `Mode::Population`.
+fn require_mode(mode: &Mode<f64>, frequency: bool, model: &str) -> Result<()> {
+    if matches!(mode, Mode::Frequency { .. }) == frequency {
+        return Ok(());
     }
+    let required = if frequency { "Frequency" } else { "Population" };
+    Err(Error::new(
+        ErrorKind::InvalidInput,
+        format!("{model} requires Mode::{required}"),
+    ))
 }
 
-/// Integrate a single trajectory and persist aggregate signal output.
+/// Integrate a single well-mixed trajectory of user-defined dynamics.
 ///
 /// Details:
-/// - Purpose: Runs one trajectory and writes signal snapshots to disk.
+/// - Purpose: Runs the shared integrator -> sanitize -> noise -> snapshot loop
+///   for any `Dynamics` reaction term. Invariants follow `gs_i.mode`:
+///   frequency states are renormalized onto the simplex, population states
+///   get cutoff and carrying capacity.
 /// - Parameters:
 ///   - `gs_i`: Initial state consumed by the solver.
-///   - `interaction_matrix`: Square interaction matrix `V`.
-///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
-///   - `noise`: Optional post-step stochastic update.
+///   - `dynamics`: Reaction term of the whole community.
+///   - `noise`: Optional noise; SDE schemes replace the integrator step.
+///     Simplex-centred kinds are rejected for population states.
+///   - `seed`: Key and stream of the noise generator.
+///   - `integrator`: Raw step scheme; implicit schemes need
+///     `Dynamics::jacobian`. Ignored when `noise.uses_sde_scheme()`.
 ///   - `dt`: Step size.
 ///   - `num_steps`: Number of integration steps.
 ///   - `save_interval`: Save every Nth step; `t = 0` is always saved.
 ///   - `output_path`: Directory for signal JSON output.
 ///   - `progress_counter`: Optional shared progress counter.
-pub fn solve(
+///   - `termination`: Explicit termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_dynamics(
     gs_i: SystemState<f64>,                 // initial state (consumed)
-    interaction_matrix: &Array2<f64>,       // V
-    growth_vector: Option<&Array1<f64>>,    // g override
+    dynamics: &dyn Dynamics,                // reaction term
     noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
     dt: f64,                                // step size
     num_steps: usize,                       // number of steps
     save_interval: usize,                   // save every N steps
     output_path: &Path,                     // signal output target
     progress_counter: Option<&AtomicUsize>, // optional progress counter
-) -> Result<SystemState<f64>> {
-    Ok(solve_with_termination(
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    solve_dynamics_with_events(
         gs_i,
-        interaction_matrix,
-        growth_vector,
+        dynamics,
         noise,
+        seed,
+        integrator,
         dt,
         num_steps,
         save_interval,
         output_path,
         progress_counter,
-        TerminationConfig::disabled(),
-    )?
-    .final_state)
+        termination,
+        &EventSchedule::new(),
+    )
 }
 
-/// Integrate a single trajectory with explicit termination configuration.
-pub fn solve_with_termination(
+/// Integrate a single well-mixed trajectory with scheduled species introductions.
+///
+/// Details:
+/// - Purpose: Same as `solve_dynamics`, but applies `events` in order after
+///   each step's snapshot (see `solvers::events`). Applied events are marked
+///   in the signal stream and returned in `SolveOutcome.events`. Termination
+///   checks start once every event has fired.
+/// - Parameters:
+///   - `events`: Ordered introductions; cell placements are rejected.
+///   - (others): As in `solve_dynamics`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_dynamics_with_events(
     mut gs_i: SystemState<f64>,             // initial state (consumed)
-    interaction_matrix: &Array2<f64>,       // V
-    growth_vector: Option<&Array1<f64>>,    // g override
+    dynamics: &dyn Dynamics,                // reaction term
     noise: Noise,                           // noise model
+    seed: RngSeed,                          // noise random stream
+    integrator: &mut dyn Integrator,        // raw step scheme
     dt: f64,                                // step size
     num_steps: usize,                       // number of steps
     save_interval: usize,                   // save every N steps
     output_path: &Path,                     // signal output target
     progress_counter: Option<&AtomicUsize>, // optional progress counter
     termination: TerminationConfig,         // explicit termination behavior
+    events: &EventSchedule,                 // scheduled introductions
 ) -> Result<SolveOutcome> {
-    let d = interaction_matrix.nrows(); // assumed square by caller / upstream validation
+    let d = gs_i.state.len();
     if save_interval == 0 {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
             "save_interval must be >= 1",
         ));
     }
-
-    // Own g for inner-loop reuse (avoid Option branches per step).
-    let growth_vector_owned: Array1<f64> = growth_vector
-        .map(|x| x.to_owned())
-        .unwrap_or_else(|| Array1::zeros(d));
+    noise.validate_for(&gs_i.mode)?;
 
     // Enforce invariants at t=0.
     gs_i.sanitize();
@@ -283,35 +956,242 @@ pub fn solve_with_termination(
     let mode0 = gs_curr.mode.clone();
     let mut gs_next = SystemState::empty(mode0, 0, d, None);
 
-    // Scratch / noise context / RNG for the whole run.
-    let mut sc = Rk4Scratch::new(d);
+    // Vector field / noise context / RNG for the whole run.
+    let mut field = WellMixedField(dynamics, 0.0);
     let mut noise_ctx = NoiseContext::new(d);
-    let mut rng = SmallRng::from_rng(&mut rand::rng());
+    let mut sde_ctx = SdeContext::default();
+    let mut rng = seed.rng();
     let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut event_queue = EventQueue::new(events, &gs_curr, d, None)?;
+    let mut event_log = Vec::new();
+    let mut extinctions = Vec::new();
+    let frequency = matches!(gs_curr.mode, Mode::Frequency { .. });
 
-    // Main loop: deterministic RK4 -> sanitize -> stochastic -> snapshot.
+    // Main loop: deterministic step -> sanitize -> stochastic -> snapshot.
     let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
     let mut steps_run = 0usize;
     let mut termination_reason = TerminationReason::MaxSteps;
     for step in 1..=num_steps {
-        rk4_step_inplace_raw(
-            &gs_curr.state,
-            &growth_vector_owned,
-            interaction_matrix,
-            dt,
-            &mut sc,
-            &mut gs_next.state,
-        );
+        // Physical time at the start of this step.
+        let t = gs_curr.physical_time;
+        field.1 = t;
+
+        if noise.uses_sde_scheme() {
+            sde_step_inplace(
+                dynamics,
+                noise,
+                t,
+                gs_curr.state.as_slice().expect("state is contiguous"),
+                dt,
+                &mut sde_ctx,
+                &mut rng,
+                gs_next.state.as_slice_mut().expect("state is contiguous"),
+            )?;
 
-        gs_next.sanitize();
+            gs_next.sanitize();
+        } else {
+            integrator.step_at(
+                &mut field,
+                t,
+                gs_curr.state.as_slice().expect("state is contiguous"),
+                dt,
+                gs_next.state.as_slice_mut().expect("state is contiguous"),
+            )?;
 
-        apply_noise_inplace(&mut gs_next, noise, dt, &mut noise_ctx, &mut rng);
+            gs_next.sanitize();
+
+            apply_noise_inplace(&mut gs_next, noise, dt, &mut noise_ctx, &mut rng);
+        }
 
         gs_next.time = start_time + step;
+        gs_next.physical_time = start_physical_time + step as f64 * dt;
 
         // Advance current state and optionally save a snapshot.
         std::mem::swap(&mut gs_curr, &mut gs_next);
         steps_run = step;
+        record_extinctions(
+            gs_next.state.as_slice().expect("state is contiguous"),
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
+
+        if step % save_interval == 0 {
+            signal_writer.push(&gs_curr)?;
+        }
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        while let Some((index, event, cause)) = event_queue.poll(&gs_curr, step)? {
+            let before = gs_curr.state.clone();
+            introduce_inplace(
+                gs_curr.state.as_slice_mut().expect("state is contiguous"),
+                event,
+                frequency,
+            );
+            gs_curr.sanitize();
+            record_extinctions(
+                before.as_slice().expect("state is contiguous"),
+                gs_curr.state.as_slice().expect("state is contiguous"),
+                step,
+                gs_curr.physical_time,
+                &mut extinctions,
+            );
+            signal_writer.push_event(&gs_curr, index)?;
+            event_log.push(EventRecord {
+                index,
+                step,
+                time: gs_curr.time,
+                physical_time: gs_curr.physical_time,
+                cause,
+                introductions: event.introductions.clone(),
+                cells: None,
+            });
+        }
+
+        if !event_queue.is_pending()
+            && let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            if step % save_interval != 0 {
+                signal_writer.push(&gs_curr)?;
+            }
+            break;
+        }
+    }
+
+    let signal_stats = signal_writer.finish()?;
+
+    Ok(SolveOutcome {
+        final_state: gs_curr,
+        steps_run,
+        reason: termination_reason,
+        signal_stats,
+        space_stats: None,
+        step_stats: None,
+        events: event_log,
+        extinctions,
+    })
+}
+
+/// Integrate a single well-mixed trajectory in log coordinates.
+///
+/// Details:
+/// - Purpose: Steps `x_i = ln u_i` with `dx_i/dt = f_i(u) / u_i` (see
+///   `solvers::log_space`), so rare species stay exactly positive instead of
+///   being removed by the cutoff. Frequency states are recovered with a
+///   log-sum-exp normalisation; population states keep their carrying
+///   capacity. The cutoff of `gs_i.mode` is skipped but kept on the state,
+///   and every saved state is `exp(x)` in the usual linear coordinates. A
+///   step that blows up (`NaN`, `+∞`, or overflow) returns
+///   `ErrorKind::InvalidData` instead of being read as an extinction. A
+///   source term that acts on a species at zero (such as `Immigration`) has
+///   no finite per-capita rate and is rejected with `ErrorKind::InvalidInput`.
+/// - Parameters:
+///   - `dynamics`: Reaction term in linear coordinates; its
+///     `per_capita_at` rate is integrated.
+///   - `integrator`: Raw step scheme; implicit schemes are rejected because
+///     the log-space field has no Jacobian.
+///   - (others): As in `solve_dynamics`.
+///
+/// Noise and events are not supported in log coordinates.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_log_space(
+    mut gs_i: SystemState<f64>,             // initial state (consumed)
+    dynamics: &dyn Dynamics,                // reaction term
+    integrator: &mut dyn Integrator,        // raw step scheme
+    dt: f64,                                // step size
+    num_steps: usize,                       // number of steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    if save_interval == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "save_interval must be >= 1",
+        ));
+    }
+
+    // Enforce every invariant except the cutoff at t=0.
+    sanitize_without_cutoff(&mut gs_i);
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        gs_curr.state.len(),
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let frequency = matches!(gs_curr.mode, Mode::Frequency { .. });
+    let mut log_curr = vec![0.0; d];
+    let mut log_next = vec![0.0; d];
+    to_log_inplace(
+        gs_curr.state.as_slice().expect("state is contiguous"),
+        &mut log_curr,
+    );
+    let mut previous = vec![0.0; d];
+    let mut field = LogSpaceField::new(dynamics, frequency, d);
+    // `previous` doubles as scratch for the t=0 per-capita rates.
+    dynamics.per_capita_at(
+        gs_curr.physical_time,
+        gs_curr.state.as_slice().expect("state is contiguous"),
+        &mut previous,
+    );
+    if previous.iter().any(|r| !r.is_finite()) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "log-space solves need a finite per-capita rate for every species; \
+             a source such as immigration cannot revive a species at zero",
+        ));
+    }
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut extinctions = Vec::new();
+
+    // Main loop: log-space step -> normalise -> linear state -> snapshot.
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut steps_run = 0usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    for step in 1..=num_steps {
+        // Physical time at the start of this step.
+        let t = gs_curr.physical_time;
+        field.time = t;
+
+        integrator.step_at(&mut field, t, &log_curr, dt, &mut log_next)?;
+        normalize_log_inplace(&mut log_next, &gs_curr.mode)?;
+        std::mem::swap(&mut log_curr, &mut log_next);
+
+        previous.copy_from_slice(gs_curr.state.as_slice().expect("state is contiguous"));
+        from_log_inplace(
+            &log_curr,
+            frequency,
+            gs_curr.state.as_slice_mut().expect("state is contiguous"),
+        );
+        sanitize_without_cutoff(&mut gs_curr);
+        gs_curr.time = start_time + step;
+        gs_curr.physical_time = start_physical_time + step as f64 * dt;
+        steps_run = step;
+        record_extinctions(
+            &previous,
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
 
         if step % save_interval == 0 {
             signal_writer.push(&gs_curr)?;
@@ -321,14 +1201,14 @@ pub fn solve_with_termination(
             counter.store(step, Ordering::Relaxed);
         }
 
-        if let Some(checker) = termination_checker.as_mut() {
-            if let Some(reason) = checker.check(&gs_curr, step) {
-                termination_reason = reason;
-                if step % save_interval != 0 {
-                    signal_writer.push(&gs_curr)?;
-                }
-                break;
+        if let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            if step % save_interval != 0 {
+                signal_writer.push(&gs_curr)?;
             }
+            break;
         }
     }
 
@@ -340,5 +1220,882 @@ pub fn solve_with_termination(
         reason: termination_reason,
         signal_stats,
         space_stats: None,
+        step_stats: None,
+        events: Vec::new(),
+        extinctions,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::non_spatial::sde::{Calculus, SdeScheme};
+    use crate::utils::uniform_mutation_matrix;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_non_spatial_rk4_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn glv_logistic_growth_approaches_equilibrium() {
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![0.1, 0.2],
+            None,
+        );
+        let interaction_matrix = array![[-1.0, 0.0], [0.0, -0.5]];
+        let growth_vector = array![1.0, 1.0];
+        let output_path = temp_output_dir("logistic");
+
+        let out = solve_glv(
+            gs,
+            &interaction_matrix,
+            Some(&growth_vector),
+            Noise::none(),
+            0.01,
+            2_000,
+            500,
+            &output_path,
+            None,
+        )
+        .expect("solve succeeds");
+
+        assert!((out.state[0] - 1.0).abs() < 1e-6);
+        assert!((out.state[1] - 2.0).abs() < 1e-6);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn glv_respects_carrying_capacity_and_rejects_frequency_mode() {
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: Some(3.0),
+            },
+            0,
+            array![1.0, 1.0],
+            None,
+        );
+        let interaction_matrix = Array2::zeros((2, 2));
+        let growth_vector = array![1.0, 1.0];
+        let output_path = temp_output_dir("capacity");
+
+        let out = solve_glv(
+            gs,
+            &interaction_matrix,
+            Some(&growth_vector),
+            Noise::none(),
+            0.01,
+            200,
+            100,
+            &output_path,
+            None,
+        )
+        .expect("solve succeeds");
+        assert!((out.state.sum() - 3.0).abs() < 1e-9);
+
+        let frequency =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let err = solve_glv(
+            frequency,
+            &interaction_matrix,
+            None,
+            Noise::none(),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+        )
+        .err()
+        .expect("frequency mode rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn immigration_recolonizes_extinct_species_in_both_modes() {
+        let output_path = temp_output_dir("immigration");
+        let rates = array![0.0, 0.1];
+        let run = |mode, state, v: &Array2<f64>, g: &Array1<f64>, dt| {
+            solve_with_immigration(
+                SystemState::from_arrays(mode, 0, state, None),
+                v,
+                Some(g),
+                &rates,
+                Noise::none(),
+                RngSeed::new(0),
+                &mut Rk4::default(),
+                dt,
+                4_000,
+                1_000,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+        };
+        let population = Mode::Population {
+            cutoff: Some(1e-4),
+            carrying_capacity: None,
+        };
+
+        // n_2' = -n_2 - n_2^2 + 0.1 settles at (sqrt(1.4) - 1) / 2.
+        let v = array![[-1.0, 0.0], [0.0, -1.0]];
+        let out = run(
+            population.clone(),
+            array![0.5, 0.0],
+            &v,
+            &array![1.0, -1.0],
+            0.01,
+        )
+        .expect("GLV solve succeeds");
+        assert!((out.final_state.state[0] - 1.0).abs() < 1e-6);
+        assert!((out.final_state.state[1] - (1.4_f64.sqrt() - 1.0) / 2.0).abs() < 1e-6);
+
+        // ν_2' = -ν_2 ν_1 + 0.1 - 0.1 ν_2 settles at ν_2 = 0.1 on the simplex.
+        let out = run(
+            Mode::Frequency { cutoff: Some(1e-4) },
+            array![1.0, 0.0],
+            &Array2::zeros((2, 2)),
+            &array![1.0, 0.0],
+            0.01,
+        )
+        .expect("replicator solve succeeds");
+        assert!((out.final_state.state[1] - 0.1).abs() < 1e-6);
+        assert!((out.final_state.state.sum() - 1.0).abs() < 1e-12);
+
+        // An inflow below the cutoff per step would be erased by sanitize.
+        let err = run(population, array![0.5, 0.0], &v, &array![1.0, -1.0], 1e-4)
+            .err()
+            .expect("sub-cutoff inflow is rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn sparse_interactions_match_the_dense_solvers_in_both_modes() {
+        let output_path = temp_output_dir("sparse");
+        let v = array![[-1.0, 0.0, -0.4], [0.5, -1.0, 0.0], [0.0, 0.0, -0.8]];
+        let g = array![1.0, 0.2, 0.5];
+        let csr = CsrMatrix::from_dense(&v);
+        let population = Mode::Population {
+            cutoff: Some(1e-9),
+            carrying_capacity: None,
+        };
+
+        for mode in [population, Mode::Frequency { cutoff: Some(1e-9) }] {
+            let gs = SystemState::from_arrays(mode.clone(), 0, array![0.2, 0.3, 0.5], None);
+            let dense = match mode {
+                Mode::Population { .. } => solve_glv_with_integrator(
+                    gs.clone(),
+                    &v,
+                    Some(&g),
+                    Noise::none(),
+                    RngSeed::new(0),
+                    &mut Rk4::default(),
+                    0.01,
+                    1_000,
+                    100,
+                    &output_path,
+                    None,
+                    TerminationConfig::disabled(),
+                ),
+                Mode::Frequency { .. } => solve_with_integrator(
+                    gs.clone(),
+                    &v,
+                    Some(&g),
+                    Noise::none(),
+                    RngSeed::new(0),
+                    &mut Rk4::default(),
+                    0.01,
+                    1_000,
+                    100,
+                    &output_path,
+                    None,
+                    TerminationConfig::disabled(),
+                ),
+            }
+            .expect("dense solve succeeds");
+            let sparse = solve_sparse(
+                gs,
+                &csr,
+                Some(&g),
+                Noise::none(),
+                RngSeed::new(0),
+                &mut Rk4::default(),
+                0.01,
+                1_000,
+                100,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .expect("sparse solve succeeds");
+
+            assert_eq!(dense.final_state.state, sparse.final_state.state);
+        }
+
+        let err = solve_sparse(
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None),
+            &csr,
+            None,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            10,
+            1,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .err()
+        .expect("shape mismatch is rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn higher_order_rejects_v_g_and_b_that_do_not_match_the_state() {
+        let gs =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let output_path = temp_output_dir("higher_order_shapes");
+        let v2 = Array2::zeros((2, 2));
+        let v3 = Array2::zeros((3, 3));
+        let g3 = array![0.0, 0.0, 0.0];
+        let b2 = InteractionTensor::sparse(2).entry(0, 1, 1, 1.0);
+        let b3 = InteractionTensor::sparse(3).entry(2, 2, 2, 1.0);
+
+        // V and B agree with each other but not with the two-species state.
+        for (v, g, b) in [(&v3, None, &b3), (&v2, Some(&g3), &b2), (&v2, None, &b3)] {
+            let err = solve_higher_order(
+                gs.clone(),
+                v,
+                g,
+                b,
+                Noise::none(),
+                RngSeed::new(0),
+                &mut Rk4::default(),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .err()
+            .expect("shape mismatch rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn glv_and_replicator_reject_v_and_g_that_do_not_match_the_state() {
+        let population = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![0.5, 0.5],
+            None,
+        );
+        let frequency =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let output_path = temp_output_dir("glv_shapes");
+        let v2 = Array2::zeros((2, 2));
+        let v3 = Array2::zeros((3, 3));
+        let g3 = array![0.0, 0.0, 0.0];
+
+        for (v, g) in [(&v3, None), (&v2, Some(&g3))] {
+            let glv = solve_glv_with_termination(
+                population.clone(),
+                v,
+                g,
+                Noise::none(),
+                RngSeed::new(0),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            );
+            let replicator = solve_with_termination(
+                frequency.clone(),
+                v,
+                g,
+                Noise::none(),
+                RngSeed::new(0),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            );
+            for result in [glv, replicator] {
+                let err = result.err().expect("shape mismatch rejected");
+                assert_eq!(err.kind(), ErrorKind::InvalidInput);
+            }
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn forced_rejects_v_g_and_forcing_that_do_not_match_the_state() {
+        let gs =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let output_path = temp_output_dir("forced_shapes");
+        let v2 = Array2::zeros((2, 2));
+        let v3 = Array2::zeros((3, 3));
+        let g3 = array![0.0, 0.0, 0.0];
+        let seasonal2 = Forcing::seasonal(array![0.1, 0.1], 1.0, 0.0);
+        let seasonal3 = Forcing::seasonal(array![0.1, 0.1, 0.1], 1.0, 0.0);
+        let regime3 = Forcing::piecewise().regime(1.0, Some(g3.clone()), None);
+
+        for (v, g, forcing) in [
+            (&v3, None, &seasonal3),
+            (&v2, Some(&g3), &seasonal2),
+            (&v2, None, &seasonal3),
+            (&v2, None, &regime3),
+        ] {
+            let err = solve_forced(
+                gs.clone(),
+                v,
+                g,
+                forcing,
+                Noise::none(),
+                RngSeed::new(0),
+                &mut Rk4::default(),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .err()
+            .expect("shape mismatch rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn rosenbrock_handles_stiff_self_limitation_at_large_dt() {
+        let make_state = || {
+            SystemState::from_arrays(
+                Mode::Population {
+                    cutoff: None,
+                    carrying_capacity: None,
+                },
+                0,
+                array![0.9, 0.1],
+                None,
+            )
+        };
+        // One fast, strongly self-limited species next to a slow one.
+        let interaction_matrix = array![[-1_000.0, 0.0], [0.0, -1.0]];
+        let growth_vector = array![1_000.0, 1.0];
+        let output_path = temp_output_dir("rosenbrock_stiff");
+
+        let out = solve_glv_with_scheme(
+            make_state(),
+            &interaction_matrix,
+            Some(&growth_vector),
+            Noise::none(),
+            RngSeed::new(0),
+            Scheme::Rosenbrock,
+            0.1,
+            300,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+        assert!((out.final_state.state[0] - 1.0).abs() < 1e-9);
+        assert!((out.final_state.state[1] - 1.0).abs() < 1e-6);
+
+        // Explicit RK4 at the same dt leaves its stability region.
+        let explicit = solve_glv_with_scheme(
+            make_state(),
+            &interaction_matrix,
+            Some(&growth_vector),
+            Noise::none(),
+            RngSeed::new(0),
+            Scheme::Rk4,
+            0.1,
+            300,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+        assert!((explicit.final_state.state[0] - 1.0).abs() > 1e-3);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn user_dynamics_reuse_the_step_loop() {
+        // Logistic growth with a Holling type II harvest term.
+        let harvest = |u: &[f64], out: &mut [f64]| {
+            for (o, &n) in out.iter_mut().zip(u) {
+                *o = n * (1.0 - n / 10.0) - 2.0 * n / (1.0 + n);
+            }
+        };
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![8.0],
+            None,
+        );
+        let output_path = temp_output_dir("user_dynamics");
+
+        let out = solve_dynamics(
+            gs,
+            &harvest,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            5_000,
+            1_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        // Stable root of (1 - n/10)(1 + n) = 2: n = (9 + sqrt(41)) / 2.
+        let n_star = (9.0 + 41.0_f64.sqrt()) / 2.0;
+        assert!((out.final_state.state[0] - n_star).abs() < 1e-6);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn replicator_mutator_reaches_the_quasispecies_equilibrium() {
+        // Master type with fitness 1, mutant with fitness 0, symmetric rate mu:
+        // dν_1/dt = (1 - mu) ν_1 - ν_1^2, so ν_1* = 1 - mu.
+        let mu = 0.1;
+        let mutation_matrix = array![[1.0 - mu, mu], [mu, 1.0 - mu]];
+        let growth_vector = array![1.0, 0.0];
+        let gs =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let output_path = temp_output_dir("mutator");
+
+        let out = solve_replicator_mutator_with_termination(
+            gs,
+            &Array2::zeros((2, 2)),
+            Some(&growth_vector),
+            &mutation_matrix,
+            Noise::none(),
+            RngSeed::new(0),
+            0.01,
+            5_000,
+            1_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+        assert!((out.final_state.state[0] - (1.0 - mu)).abs() < 1e-9);
+
+        let err = solve_replicator_mutator(
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None),
+            &Array2::zeros((2, 2)),
+            None,
+            &array![[0.5, 0.6], [0.0, 1.0]],
+            Noise::none(),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+        )
+        .err()
+        .expect("non-stochastic Q rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // V, g, and Q must all match the two-species state.
+        let v3 = Array2::zeros((3, 3));
+        let g3 = array![0.0, 0.0, 0.0];
+        let q3 = uniform_mutation_matrix(3, mu);
+        let v2 = Array2::zeros((2, 2));
+        for (v, g, q) in [
+            (&v3, None, &q3),
+            (&v2, Some(&g3), &mutation_matrix),
+            (&v2, None, &q3),
+        ] {
+            let err = solve_replicator_mutator(
+                SystemState::from_arrays(
+                    Mode::Frequency { cutoff: None },
+                    0,
+                    array![0.5, 0.5],
+                    None,
+                ),
+                v,
+                g,
+                q,
+                Noise::none(),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+            )
+            .err()
+            .expect("shape mismatch rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn equal_seeds_replay_noisy_runs() {
+        let interaction_matrix = array![[0.0, 1.0, -1.0], [-1.0, 0.0, 1.0], [1.0, -1.0, 0.0]];
+        let output_path = temp_output_dir("seeded");
+        let run = |seed: RngSeed| {
+            let gs = SystemState::from_arrays(
+                Mode::Frequency { cutoff: None },
+                0,
+                array![0.5, 0.3, 0.2],
+                None,
+            );
+            solve_with_termination(
+                gs,
+                &interaction_matrix,
+                None,
+                Noise::demographic_gaussian(0.05),
+                seed,
+                0.01,
+                200,
+                100,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .expect("solve succeeds")
+            .final_state
+            .state
+        };
+
+        assert_eq!(run(RngSeed::new(9)), run(RngSeed::new(9)));
+        assert_ne!(run(RngSeed::new(9)), run(RngSeed::new(9).replicate(1)));
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn glv_rejects_simplex_centred_noise() {
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![1.0, 1.0],
+            None,
+        );
+        let interaction_matrix = Array2::zeros((2, 2));
+        let output_path = temp_output_dir("simplex_noise");
+
+        let err = solve_glv(
+            gs.clone(),
+            &interaction_matrix,
+            None,
+            Noise::demographic_gaussian(0.1),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+        )
+        .err()
+        .expect("simplex noise rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // The check lives in the shared loop, so user dynamics get it too.
+        let decay = |u: &[f64], out: &mut [f64]| {
+            for (o, &n) in out.iter_mut().zip(u) {
+                *o = -n;
+            }
+        };
+        let err = solve_dynamics(
+            gs,
+            &decay,
+            Noise::demographic_gaussian(0.1),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .err()
+        .expect("simplex noise rejected for user dynamics");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn sde_schemes_replace_the_integrator_step_and_keep_the_simplex() {
+        let interaction_matrix = array![[0.0, 1.0, -1.0], [-1.0, 0.0, 1.0], [1.0, -1.0, 0.0]];
+        let output_path = temp_output_dir("sde_simplex");
+
+        for (scheme, calculus) in [
+            (SdeScheme::EulerMaruyama, Calculus::Ito),
+            (SdeScheme::StochasticHeun, Calculus::Stratonovich),
+        ] {
+            let gs = SystemState::from_arrays(
+                Mode::Frequency { cutoff: None },
+                0,
+                array![0.2, 0.3, 0.5],
+                None,
+            );
+            let noise = Noise::demographic_gaussian(0.1)
+                .with_scheme(scheme)
+                .with_calculus(calculus);
+
+            let out = solve(
+                gs,
+                &interaction_matrix,
+                None,
+                noise,
+                0.01,
+                200,
+                50,
+                &output_path,
+                None,
+            )
+            .expect("solve succeeds");
+
+            assert!((out.state.sum() - 1.0).abs() < 1e-12);
+            assert!(out.state.iter().all(|&v| v >= 0.0));
+        }
+
+        for noise in [
+            Noise::demographic_gaussian(0.1).with_scheme(SdeScheme::Milstein),
+            Noise::demographic_gaussian(0.1).with_calculus(Calculus::Stratonovich),
+        ] {
+            let gs = SystemState::from_arrays(
+                Mode::Frequency { cutoff: None },
+                0,
+                array![0.2, 0.3, 0.5],
+                None,
+            );
+            let err = solve(
+                gs,
+                &interaction_matrix,
+                None,
+                noise,
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+            )
+            .err()
+            .expect("undefined noise combination rejected");
+            assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn log_space_immigration_matches_linear_and_rejects_zero_starts() {
+        let interaction_matrix = array![[-1.0, 0.0], [0.0, -1.0]];
+        let growth_vector = array![1.0, 0.5];
+        let rates = array![0.0, 0.2];
+        let dynamics = Immigration::population(
+            GlvPopulation {
+                interaction_matrix: &interaction_matrix,
+                growth_vector: &growth_vector,
+            },
+            &rates,
+        );
+        let output_path = temp_output_dir("log_space_immigration");
+        let initial = |state| {
+            SystemState::from_arrays(
+                Mode::Population {
+                    cutoff: None,
+                    carrying_capacity: None,
+                },
+                0,
+                state,
+                None,
+            )
+        };
+
+        let linear = solve_dynamics(
+            initial(array![0.5, 0.1]),
+            &dynamics,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            3_000,
+            3_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("linear solve succeeds");
+        let log = solve_log_space(
+            initial(array![0.5, 0.1]),
+            &dynamics,
+            &mut Rk4::default(),
+            0.01,
+            3_000,
+            3_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("log-space solve succeeds");
+        // n_2* solves 0.5 n - n² + 0.2 = 0.
+        let n2 = (0.5 + (0.25f64 + 0.8).sqrt()) / 2.0;
+        for out in [&linear, &log] {
+            assert!((out.final_state.state[1] - n2).abs() < 1e-6);
+        }
+
+        let err = solve_log_space(
+            initial(array![0.5, 0.0]),
+            &dynamics,
+            &mut Rk4::default(),
+            0.01,
+            1,
+            1,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .err()
+        .expect("immigration onto a zero species rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn log_space_keeps_rare_species_that_the_linear_cutoff_removes() {
+        let interaction_matrix = Array2::zeros((2, 2));
+        let growth_vector = array![0.0, -1.0];
+        let dynamics = Replicator {
+            interaction_matrix: &interaction_matrix,
+            growth_vector: &growth_vector,
+        };
+        let output_path = temp_output_dir("log_space");
+        let initial = || {
+            SystemState::from_arrays(
+                Mode::Frequency {
+                    cutoff: Some(1e-30),
+                },
+                0,
+                array![0.5, 0.5],
+                None,
+            )
+        };
+
+        // ν_2 / ν_1 = e^{-t} reaches e^{-100} ≈ 4e-44 at t = 100.
+        let linear = solve_dynamics(
+            initial(),
+            &dynamics,
+            Noise::none(),
+            RngSeed::new(0),
+            &mut Rk4::default(),
+            0.01,
+            10_000,
+            10_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("linear solve succeeds");
+        assert_eq!(linear.final_state.state[1], 0.0);
+
+        let log = solve_log_space(
+            initial(),
+            &dynamics,
+            &mut Rk4::default(),
+            0.01,
+            10_000,
+            10_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("log-space solve succeeds");
+        let state = &log.final_state.state;
+        assert!(state[1] > 0.0);
+        assert!((state[1].ln() - state[0].ln() + 100.0).abs() < 1e-9);
+        assert!(matches!(
+            log.final_state.mode,
+            Mode::Frequency { cutoff: Some(c) } if c == 1e-30
+        ));
+
+        // Population coordinates converge to the same GLV equilibrium.
+        let interaction_matrix = array![[-1.0, 0.0], [0.0, -0.5]];
+        let growth_vector = array![1.0, 1.0];
+        let glv = GlvPopulation {
+            interaction_matrix: &interaction_matrix,
+            growth_vector: &growth_vector,
+        };
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![0.1, 0.2],
+            None,
+        );
+        let out = solve_log_space(
+            gs.clone(),
+            &glv,
+            &mut Rk4::default(),
+            0.01,
+            2_000,
+            500,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("log-space solve succeeds");
+        assert!((out.final_state.state[0] - 1.0).abs() < 1e-6);
+        assert!((out.final_state.state[1] - 2.0).abs() < 1e-6);
+
+        assert!(
+            solve_log_space(
+                gs,
+                &glv,
+                Scheme::Rosenbrock.integrator().as_mut(),
+                0.01,
+                1,
+                1,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .is_err()
+        );
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
@@ -0,0 +1,629 @@
+/*!
+Well-mixed adaptive Dormand–Prince solver.
+
+Purpose:
+    This module implements an embedded RK45 (Dormand–Prince 5(4)) integrator
+    for the well-mixed replicator, GLV, and user `Dynamics` vector fields. The
+    step size is
+    controlled from the embedded error estimate with relative and absolute
+    tolerances, rejected steps are retried with a smaller step, and step
+    sizes stay within configured bounds.
+
+Evolution contract:
+    One accepted step follows this sequence: RK45 raw step, error check,
+    `SystemState::sanitize`, then snapshot check. Steps are shortened so that
+    every multiple of `save_time_interval` is hit exactly, which keeps signal
+    samples on a regular physical-time grid. Signal `time` records the number
+    of accepted steps; the physical time of sample `k` is
+    `k * save_time_interval` (or `t_end` for a final partial interval).
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+
+use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::{
+    Dynamics, GlvPopulation, Replicator, growth_vector_or_zeros, validate_interactions,
+};
+use crate::solvers::extinctions::record_extinctions;
+use crate::solvers::termination::{
+    SolveOutcome, StepStats, TerminationChecker, TerminationConfig, TerminationReason,
+};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+
+// Dormand–Prince 5(4) tableau; stage abscissae feed time-dependent dynamics.
+const C2: f64 = 1.0 / 5.0;
+const C3: f64 = 3.0 / 10.0;
+const C4: f64 = 4.0 / 5.0;
+const C5: f64 = 8.0 / 9.0;
+const A21: f64 = 1.0 / 5.0;
+const A31: f64 = 3.0 / 40.0;
+const A32: f64 = 9.0 / 40.0;
+const A41: f64 = 44.0 / 45.0;
+const A42: f64 = -56.0 / 15.0;
+const A43: f64 = 32.0 / 9.0;
+const A51: f64 = 19372.0 / 6561.0;
+const A52: f64 = -25360.0 / 2187.0;
+const A53: f64 = 64448.0 / 6561.0;
+const A54: f64 = -212.0 / 729.0;
+const A61: f64 = 9017.0 / 3168.0;
+const A62: f64 = -355.0 / 33.0;
+const A63: f64 = 46732.0 / 5247.0;
+const A64: f64 = 49.0 / 176.0;
+const A65: f64 = -5103.0 / 18656.0;
+
+// Fifth-order weights; the seventh stage is evaluated at this solution.
+const B1: f64 = 35.0 / 384.0;
+const B3: f64 = 500.0 / 1113.0;
+const B4: f64 = 125.0 / 192.0;
+const B5: f64 = -2187.0 / 6784.0;
+const B6: f64 = 11.0 / 84.0;
+
+// Difference between fifth- and fourth-order weights.
+const E1: f64 = 71.0 / 57600.0;
+const E3: f64 = -71.0 / 16695.0;
+const E4: f64 = 71.0 / 1920.0;
+const E5: f64 = -17253.0 / 339200.0;
+const E6: f64 = 22.0 / 525.0;
+const E7: f64 = -1.0 / 40.0;
+
+const SAFETY: f64 = 0.9;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 5.0;
+
+/// Step-size control and time-grid configuration for adaptive solves.
+///
+/// Details:
+/// - Purpose: Groups tolerances, step bounds, and the physical-time horizon
+///   and save grid of one adaptive run.
+/// - Parameters:
+///   - `rtol`: Relative tolerance on each component.
+///   - `atol`: Absolute tolerance on each component.
+///   - `dt_initial`: First trial step size.
+///   - `dt_min`: Smallest allowed step; a step at this size is accepted even
+///     if it fails the tolerance test.
+///   - `dt_max`: Largest allowed step.
+///   - `t_end`: Physical end time.
+///   - `save_time_interval`: Physical time between signal samples.
+///   - `max_steps`: Upper bound on accepted steps.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct AdaptiveConfig {
+    pub rtol: f64,
+    pub atol: f64,
+    pub dt_initial: f64,
+    pub dt_min: f64,
+    pub dt_max: f64,
+    pub t_end: f64,
+    pub save_time_interval: f64,
+    pub max_steps: usize,
+}
+
+impl AdaptiveConfig {
+    fn validate(&self) -> Result<()> {
+        let invalid = |message| Error::new(ErrorKind::InvalidInput, message);
+
+        if !self.rtol.is_finite() || self.rtol < 0.0 || !self.atol.is_finite() || self.atol < 0.0 {
+            return Err(invalid("rtol and atol must be finite and nonnegative"));
+        }
+        if self.rtol == 0.0 && self.atol == 0.0 {
+            return Err(invalid("rtol and atol must not both be zero"));
+        }
+        if !self.dt_min.is_finite() || self.dt_min <= 0.0 {
+            return Err(invalid("dt_min must be finite and positive"));
+        }
+        if !self.dt_max.is_finite() || self.dt_max < self.dt_min {
+            return Err(invalid("dt_max must be finite and >= dt_min"));
+        }
+        if !self.dt_initial.is_finite() || self.dt_initial <= 0.0 {
+            return Err(invalid("dt_initial must be finite and positive"));
+        }
+        if !self.t_end.is_finite() || self.t_end < 0.0 {
+            return Err(invalid("t_end must be finite and nonnegative"));
+        }
+        if !self.save_time_interval.is_finite() || self.save_time_interval <= 0.0 {
+            return Err(invalid("save_time_interval must be finite and positive"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Scratch buffers for RK45 (avoid repeated allocations).
+///
+/// Details:
+/// - Purpose: Owns stage derivatives, the stage state, and the error
+///   estimate for the hot integration loop.
+/// - Parameters:
+///   - (none): Construct with `Rk45Scratch::new`.
+struct Rk45Scratch {
+    k1: Array1<f64>,
+    k2: Array1<f64>,
+    k3: Array1<f64>,
+    k4: Array1<f64>,
+    k5: Array1<f64>,
+    k6: Array1<f64>,
+    k7: Array1<f64>,
+    tmp: Array1<f64>,
+    err: Array1<f64>,
+}
+
+impl Rk45Scratch {
+    #[inline]
+    fn new(d: usize) -> Self {
+        Self {
+            k1: Array1::zeros(d),
+            k2: Array1::zeros(d),
+            k3: Array1::zeros(d),
+            k4: Array1::zeros(d),
+            k5: Array1::zeros(d),
+            k6: Array1::zeros(d),
+            k7: Array1::zeros(d),
+            tmp: Array1::zeros(d),
+            err: Array1::zeros(d),
+        }
+    }
+}
+
+/// One Dormand–Prince trial step writing the fifth-order solution into `out`.
+///
+/// Details:
+/// - Purpose: Advances one raw trial step and returns the scaled RMS error
+///   norm of the embedded fourth-order estimate. A norm `<= 1` meets the
+///   tolerances.
+/// - Parameters:
+///   - `t`: Step start time.
+///   - `nu`: Current state.
+///   - `dynamics`: Reaction term.
+///   - `h`: Trial step size.
+///   - `config`: Tolerances.
+///   - `sc`: Reusable RK45 scratch storage.
+///   - `out`: Raw next-state destination.
+fn dopri_step_inplace_raw(
+    t: f64,
+    nu: &Array1<f64>,
+    dynamics: &dyn Dynamics,
+    h: f64,
+    config: &AdaptiveConfig,
+    sc: &mut Rk45Scratch,
+    out: &mut Array1<f64>,
+) -> f64 {
+    let d = nu.len();
+    let rhs = |c: f64, x: &Array1<f64>, k: &mut Array1<f64>| {
+        dynamics.reaction_at(
+            t + c * h,
+            x.as_slice().expect("state is contiguous"),
+            k.as_slice_mut().expect("scratch is contiguous"),
+        )
+    };
+
+    rhs(0.0, nu, &mut sc.k1);
+
+    for i in 0..d {
+        sc.tmp[i] = nu[i] + h * A21 * sc.k1[i];
+    }
+    rhs(C2, &sc.tmp, &mut sc.k2);
+
+    for i in 0..d {
+        sc.tmp[i] = nu[i] + h * (A31 * sc.k1[i] + A32 * sc.k2[i]);
+    }
+    rhs(C3, &sc.tmp, &mut sc.k3);
+
+    for i in 0..d {
+        sc.tmp[i] = nu[i] + h * (A41 * sc.k1[i] + A42 * sc.k2[i] + A43 * sc.k3[i]);
+    }
+    rhs(C4, &sc.tmp, &mut sc.k4);
+
+    for i in 0..d {
+        sc.tmp[i] = nu[i] + h * (A51 * sc.k1[i] + A52 * sc.k2[i] + A53 * sc.k3[i] + A54 * sc.k4[i]);
+    }
+    rhs(C5, &sc.tmp, &mut sc.k5);
+
+    for i in 0..d {
+        sc.tmp[i] = nu[i]
+            + h * (A61 * sc.k1[i]
+                + A62 * sc.k2[i]
+                + A63 * sc.k3[i]
+                + A64 * sc.k4[i]
+                + A65 * sc.k5[i]);
+    }
+    rhs(1.0, &sc.tmp, &mut sc.k6);
+
+    // Fifth-order solution; the seventh stage is evaluated at it.
+    for i in 0..d {
+        out[i] = nu[i]
+            + h * (B1 * sc.k1[i] + B3 * sc.k3[i] + B4 * sc.k4[i] + B5 * sc.k5[i] + B6 * sc.k6[i]);
+    }
+    rhs(1.0, out, &mut sc.k7);
+
+    let mut sum_sq = 0.0;
+    for i in 0..d {
+        sc.err[i] = h
+            * (E1 * sc.k1[i]
+                + E3 * sc.k3[i]
+                + E4 * sc.k4[i]
+                + E5 * sc.k5[i]
+                + E6 * sc.k6[i]
+                + E7 * sc.k7[i]);
+        let scale = config.atol + config.rtol * nu[i].abs().max(out[i].abs());
+        let ratio = sc.err[i] / scale;
+        sum_sq += ratio * ratio;
+    }
+
+    for x in out.iter_mut() {
+        if !x.is_finite() || *x <= 0.0 {
+            *x = 0.0;
+        }
+    }
+
+    let norm = (sum_sq / d.max(1) as f64).sqrt();
+    if norm.is_finite() {
+        norm
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Integrate a single adaptive replicator trajectory and persist signal output.
+///
+/// Details:
+/// - Purpose: Runs one replicator trajectory up to `config.t_end` with
+///   embedded error control and writes signal snapshots on a regular
+///   physical-time grid.
+/// - Parameters:
+///   - `gs_i`: Initial state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `config`: Tolerances, step bounds, and time grid.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter (accepted steps).
+pub fn solve_adaptive(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_adaptive_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        config,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single adaptive replicator trajectory with explicit termination.
+pub fn solve_adaptive_with_termination(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = Replicator {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics_adaptive(
+        gs_i,
+        &dynamics,
+        config,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single adaptive well-mixed GLV trajectory and persist signal output.
+///
+/// Details:
+/// - Purpose: Runs one `Mode::Population` GLV trajectory up to
+///   `config.t_end` with embedded error control.
+/// - Parameters:
+///   - `gs_i`: Initial population state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `config`: Tolerances, step bounds, and time grid.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter (accepted steps).
+pub fn solve_glv_adaptive(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_glv_adaptive_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        config,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single adaptive well-mixed GLV trajectory with explicit termination.
+pub fn solve_glv_adaptive_with_termination(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    if !matches!(gs_i.mode, Mode::Population { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "well-mixed GLV requires Mode::Population",
+        ));
+    }
+    let d = gs_i.state.len();
+    validate_interactions(interaction_matrix.dim(), growth_vector, d)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = GlvPopulation {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics_adaptive(
+        gs_i,
+        &dynamics,
+        config,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single adaptive trajectory of user-defined dynamics.
+///
+/// Details:
+/// - Purpose: Runs the Dormand–Prince loop for any `Dynamics` reaction term.
+///   Invariants follow `gs_i.mode` through `SystemState::sanitize`.
+/// - Parameters:
+///   - `gs_i`: Initial state consumed by the solver.
+///   - `dynamics`: Reaction term of the whole community.
+///   - `config`: Tolerances, step bounds, and time grid.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter (accepted steps).
+///   - `termination`: Explicit termination behavior.
+pub fn solve_dynamics_adaptive(
+    mut gs_i: SystemState<f64>,             // initial state (consumed)
+    dynamics: &dyn Dynamics,                // reaction term
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    config.validate()?;
+    let d = gs_i.state.len();
+
+    // Enforce invariants at t=0.
+    gs_i.sanitize();
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        gs_curr.state.len(),
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let mode0 = gs_curr.mode.clone();
+    let mut gs_next = SystemState::empty(mode0, 0, d, None);
+    let mut sc = Rk45Scratch::new(d);
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut extinctions = Vec::new();
+
+    // Main loop: trial step -> accept/reject -> sanitize -> snapshot.
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut stats = StepStats::default();
+    let mut t = 0.0;
+    let mut h = config.dt_initial.clamp(config.dt_min, config.dt_max);
+    let mut save_index = 1usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    while t < config.t_end && stats.accepted_steps < config.max_steps {
+        let t_save = (save_index as f64 * config.save_time_interval).min(config.t_end);
+        let remaining = t_save - t;
+        let hits_save = h >= remaining;
+        let h_try = if hits_save { remaining } else { h };
+
+        let err = dopri_step_inplace_raw(
+            start_physical_time + t,
+            &gs_curr.state,
+            dynamics,
+            h_try,
+            &config,
+            &mut sc,
+            &mut gs_next.state,
+        );
+
+        // Reject and retry unless the step is already at the lower bound.
+        if err > 1.0 && h_try > config.dt_min {
+            stats.rejected_steps += 1;
+            let factor = (SAFETY * err.powf(-0.2)).max(MIN_FACTOR);
+            h = (h_try * factor).max(config.dt_min);
+            continue;
+        }
+
+        // Grow the proposal from the trial that was actually taken.
+        let factor = if err > 0.0 {
+            (SAFETY * err.powf(-0.2)).clamp(MIN_FACTOR, MAX_FACTOR)
+        } else {
+            MAX_FACTOR
+        };
+        if !hits_save || h_try >= h {
+            h = (h_try * factor).clamp(config.dt_min, config.dt_max);
+        }
+
+        gs_next.sanitize();
+
+        stats.accepted_steps += 1;
+        t = if hits_save { t_save } else { t + h_try };
+        gs_next.time = start_time + stats.accepted_steps;
+        gs_next.physical_time = start_physical_time + t;
+
+        // Advance current state and optionally save a snapshot.
+        std::mem::swap(&mut gs_curr, &mut gs_next);
+        let step = stats.accepted_steps;
+        record_extinctions(
+            gs_next.state.as_slice().expect("state is contiguous"),
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
+
+        if hits_save {
+            signal_writer.push(&gs_curr)?;
+            save_index += 1;
+        }
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        if let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            if !hits_save {
+                signal_writer.push(&gs_curr)?;
+            }
+            break;
+        }
+    }
+    stats.final_time = start_physical_time + t;
+
+    let signal_stats = signal_writer.finish()?;
+
+    Ok(SolveOutcome {
+        final_state: gs_curr,
+        steps_run: stats.accepted_steps,
+        reason: termination_reason,
+        signal_stats,
+        space_stats: None,
+        step_stats: Some(stats),
+        events: Vec::new(),
+        extinctions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_non_spatial_rk45_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    fn config(t_end: f64) -> AdaptiveConfig {
+        AdaptiveConfig {
+            rtol: 1e-8,
+            atol: 1e-10,
+            dt_initial: 1e-3,
+            dt_min: 1e-12,
+            dt_max: 1.0,
+            t_end,
+            save_time_interval: 0.5,
+            max_steps: 100_000,
+        }
+    }
+
+    #[test]
+    fn adaptive_glv_matches_logistic_solution_on_regular_save_grid() {
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            array![0.1],
+            None,
+        );
+        let interaction_matrix = array![[-1.0]];
+        let growth_vector = array![1.0];
+        let output_path = temp_output_dir("logistic");
+
+        let outcome = solve_glv_adaptive_with_termination(
+            gs,
+            &interaction_matrix,
+            Some(&growth_vector),
+            config(4.0),
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        // n(t) = 1 / (1 + 9 e^{-t}) for n(0) = 0.1.
+        let exact = 1.0 / (1.0 + 9.0 * (-4.0_f64).exp());
+        let stats = outcome.step_stats.expect("adaptive stats");
+        assert!((outcome.final_state.state[0] - exact).abs() < 1e-7);
+        assert_eq!(stats.final_time, 4.0);
+        assert_eq!(outcome.signal_stats.samples, 9);
+        assert!(stats.accepted_steps > 0);
+        assert!(stats.accepted_steps < 1_000);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn adaptive_step_rejects_oversized_initial_step() {
+        let gs =
+            SystemState::from_arrays(Mode::Frequency { cutoff: None }, 0, array![0.5, 0.5], None);
+        let interaction_matrix = array![[0.0, 20.0], [-20.0, 0.0]];
+        let output_path = temp_output_dir("reject");
+        let mut cfg = config(1.0);
+        cfg.dt_initial = 1.0;
+
+        let outcome = solve_adaptive_with_termination(
+            gs,
+            &interaction_matrix,
+            None,
+            cfg,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        let stats = outcome.step_stats.expect("adaptive stats");
+        assert!(stats.rejected_steps > 0);
+        assert!((outcome.final_state.state.sum() - 1.0).abs() < 1e-12);
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
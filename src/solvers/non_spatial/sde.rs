@@ -0,0 +1,507 @@
+/*!
+Non-spatial SDE integrators.
+
+Purpose:
+    This module advances the well-mixed state as a stochastic differential
+    equation `dX = f(X) dt + G(X) dW`, where `f` is the `Dynamics` reaction
+    term and `G` is the diffusion matrix of the configured `NoiseKind`. Drift
+    and noise are integrated together with a stated strong order, instead of
+    perturbing the state after a finished deterministic step.
+
+Schemes:
+    `EulerMaruyama` has strong order 1/2 for every kind. `Milstein` has strong
+    order 1 and is offered for the diagonal population kinds only; the
+    simplex-centred kinds couple every species to every Wiener component, so
+    Milstein would need Lévy areas. `StochasticHeun` is a predictor-corrector
+    that converges to the Stratonovich solution and is the higher-order choice
+    for the simplex-centred kinds.
+
+Calculus:
+    `Noise::calculus` states how `G(X) dW` is read. Schemes convert between
+    interpretations with the drift correction `c_i = ½ Σ_jk G_jk ∂_j G_ik`:
+    Itô `f` equals Stratonovich `f - c`. The correction is analytic for every
+    kind; extinct species (zero abundance) have zero diffusion and zero
+    correction.
+
+Evolution contract:
+    A step writes the raw next state with negative or non-finite entries
+    clamped to zero. The solver enforces representation invariants through
+    `SystemState::sanitize` afterwards, exactly as for deterministic steps.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use serde::{Deserialize, Serialize};
+
+use super::noise::{Noise, NoiseKind};
+use crate::solvers::dynamics::Dynamics;
+
+/// How noise is combined with the deterministic step.
+///
+/// Details:
+/// - Purpose: Selects the stochastic scheme used by the well-mixed solvers.
+/// - Variants:
+///   - `PostStepSplitting`: Integrator step, sanitize, then the noise update of
+///     `apply_noise_inplace` (default; Itô only, no stated order).
+///   - `EulerMaruyama`: Strong order 1/2 for every kind.
+///   - `Milstein`: Strong order 1; diagonal population kinds only.
+///   - `StochasticHeun`: Stratonovich predictor-corrector for every kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SdeScheme {
+    #[default]
+    PostStepSplitting,
+    EulerMaruyama,
+    Milstein,
+    StochasticHeun,
+}
+
+/// Stochastic calculus used to interpret `G(X) dW`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Calculus {
+    #[default]
+    Ito,
+    Stratonovich,
+}
+
+/// Reusable Wiener increments and stage buffers for SDE steps.
+///
+/// Details:
+/// - Purpose: Owns every per-step buffer so the solver loop does not allocate.
+/// - Parameters:
+///   - (none): Construct with `SdeContext::default`; buffers grow on first use.
+pub struct SdeContext {
+    dw: Vec<f64>,
+    drift: Vec<f64>,
+    drift_pred: Vec<f64>,
+    diff: Vec<f64>,
+    diff_pred: Vec<f64>,
+    corr: Vec<f64>,
+    pred: Vec<f64>,
+    normal: Normal<f64>,
+}
+
+impl Default for SdeContext {
+    fn default() -> Self {
+        Self {
+            dw: Vec::new(),
+            drift: Vec::new(),
+            drift_pred: Vec::new(),
+            diff: Vec::new(),
+            diff_pred: Vec::new(),
+            corr: Vec::new(),
+            pred: Vec::new(),
+            normal: Normal::<f64>::new(0.0, 1.0).expect("Normal(0,1) ctor"),
+        }
+    }
+}
+
+impl SdeContext {
+    #[inline]
+    fn resize_if_needed(&mut self, d: usize) {
+        for buf in [
+            &mut self.dw,
+            &mut self.drift,
+            &mut self.drift_pred,
+            &mut self.diff,
+            &mut self.diff_pred,
+            &mut self.corr,
+            &mut self.pred,
+        ] {
+            if buf.len() != d {
+                buf.resize(d, 0.0);
+            }
+        }
+    }
+}
+
+/// Write the diffusion increment `G(x) ΔW` into `out`.
+///
+/// Details:
+/// - Purpose: Evaluates the noise term of each kind, reading `x` clamped to
+///   be non-negative:
+///   - `ProportionalGaussian`: `σ ν_i (ΔW_i - Σ_j ν_j ΔW_j)`.
+///   - `DemographicGaussian`: `σ √ν_i (ΔW_i - Σ_j √ν_j ΔW_j / Σ_j √ν_j)`.
+///   - `PopulationDemographicGaussian`: `σ √n_i ΔW_i`.
+///   - `EnvironmentalGaussian`: `σ n_i ΔW_i`.
+fn diffusion_increment(kind: NoiseKind, x: &[f64], dw: &[f64], out: &mut [f64]) {
+    let d = x.len();
+    match kind {
+        NoiseKind::None => out.fill(0.0),
+
+        NoiseKind::ProportionalGaussian { sigma } => {
+            let mut mean = 0.0;
+            for j in 0..d {
+                mean += x[j].max(0.0) * dw[j];
+            }
+            for i in 0..d {
+                out[i] = sigma * x[i].max(0.0) * (dw[i] - mean);
+            }
+        }
+
+        NoiseKind::DemographicGaussian { sigma } => {
+            let mut num = 0.0;
+            let mut den = 0.0;
+            for j in 0..d {
+                let a = x[j].max(0.0).sqrt();
+                num += a * dw[j];
+                den += a;
+            }
+            let mean = if den > 0.0 { num / den } else { 0.0 };
+            for i in 0..d {
+                out[i] = sigma * x[i].max(0.0).sqrt() * (dw[i] - mean);
+            }
+        }
+
+        NoiseKind::PopulationDemographicGaussian { sigma } => {
+            for i in 0..d {
+                out[i] = sigma * x[i].max(0.0).sqrt() * dw[i];
+            }
+        }
+
+        NoiseKind::EnvironmentalGaussian { sigma } => {
+            for i in 0..d {
+                out[i] = sigma * x[i].max(0.0) * dw[i];
+            }
+        }
+    }
+}
+
+/// Write the Itô-Stratonovich drift correction `c_i = ½ Σ_jk G_jk ∂_j G_ik`.
+///
+/// Details:
+/// - Purpose: Converts drifts between calculi. With `S1 = Σ ν`, `S2 = Σ ν²`,
+///   `a_i = √ν_i`, `S = Σ a`, and `m` alive species:
+///   - `ProportionalGaussian`: `½ σ² ν_i (1 - 2ν_i + 2 S2 - S1)`.
+///   - `DemographicGaussian`: `¼ σ² (1 + S1 / S²)(1 - m a_i / S)`.
+///   - `PopulationDemographicGaussian`: `¼ σ²`.
+///   - `EnvironmentalGaussian`: `½ σ² n_i`.
+///
+///   Extinct species get zero, matching their zero diffusion.
+fn ito_correction(kind: NoiseKind, x: &[f64], out: &mut [f64]) {
+    let d = x.len();
+    match kind {
+        NoiseKind::None => out.fill(0.0),
+
+        NoiseKind::ProportionalGaussian { sigma } => {
+            let mut s1 = 0.0;
+            let mut s2 = 0.0;
+            for &xj in x {
+                let v = xj.max(0.0);
+                s1 += v;
+                s2 += v * v;
+            }
+            for i in 0..d {
+                let v = x[i].max(0.0);
+                out[i] = 0.5 * sigma * sigma * v * (1.0 - 2.0 * v + 2.0 * s2 - s1);
+            }
+        }
+
+        NoiseKind::DemographicGaussian { sigma } => {
+            let mut s1 = 0.0;
+            let mut s = 0.0;
+            let mut alive = 0usize;
+            for &xj in x {
+                if xj > 0.0 {
+                    s1 += xj;
+                    s += xj.sqrt();
+                    alive += 1;
+                }
+            }
+            for i in 0..d {
+                out[i] = if x[i] > 0.0 {
+                    0.25 * sigma
+                        * sigma
+                        * (1.0 + s1 / (s * s))
+                        * (1.0 - alive as f64 * x[i].sqrt() / s)
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        NoiseKind::PopulationDemographicGaussian { sigma } => {
+            for i in 0..d {
+                out[i] = if x[i] > 0.0 {
+                    0.25 * sigma * sigma
+                } else {
+                    0.0
+                };
+            }
+        }
+
+        NoiseKind::EnvironmentalGaussian { sigma } => {
+            for i in 0..d {
+                out[i] = 0.5 * sigma * sigma * x[i].max(0.0);
+            }
+        }
+    }
+}
+
+/// Write `f(t, x) + sign · c(x)` into `drift`, using `corr` as scratch.
+#[inline]
+fn corrected_drift(
+    dynamics: &dyn Dynamics,
+    t: f64,
+    kind: NoiseKind,
+    x: &[f64],
+    sign: f64,
+    corr: &mut [f64],
+    drift: &mut [f64],
+) {
+    dynamics.reaction_at(t, x, drift);
+    if sign != 0.0 {
+        ito_correction(kind, x, corr);
+        for i in 0..x.len() {
+            drift[i] += sign * corr[i];
+        }
+    }
+}
+
+#[inline]
+fn clamp_non_negative(out: &mut [f64]) {
+    for v in out.iter_mut() {
+        if !(v.is_finite() && *v > 0.0) {
+            *v = 0.0;
+        }
+    }
+}
+
+/// Advance `x` by one SDE step of size `dt` into `out`.
+///
+/// Details:
+/// - Purpose: Integrates drift and diffusion together with `noise.scheme`,
+///   reading `G(X) dW` in `noise.calculus`. Negative or non-finite entries
+///   are clamped to zero; the caller sanitizes afterwards.
+/// - Parameters:
+///   - `dynamics`: Drift term `f`.
+///   - `noise`: Noise kind, scheme, and calculus.
+///   - `t`: Step start time; the Heun corrector evaluates the drift at
+///     `t + dt`.
+///   - `x`: Current state.
+///   - `dt`: Step size.
+///   - `ctx`: Reusable increments and stage buffers.
+///   - `rng_local`: Random-number generator.
+///   - `out`: Destination state (same length as `x`).
+#[allow(clippy::needless_range_loop, clippy::too_many_arguments)]
+pub fn sde_step_inplace(
+    dynamics: &dyn Dynamics,
+    noise: Noise,
+    t: f64,
+    x: &[f64],
+    dt: f64,
+    ctx: &mut SdeContext,
+    rng_local: &mut impl Rng,
+    out: &mut [f64],
+) -> Result<()> {
+    let d = x.len();
+    ctx.resize_if_needed(d);
+
+    // ΔW_i = √dt η_i with η_i ~ N(0,1).
+    let sqrt_dt = dt.sqrt();
+    for w in ctx.dw.iter_mut() {
+        *w = sqrt_dt * ctx.normal.sample(rng_local);
+    }
+
+    let kind = noise.kind;
+    let stratonovich = noise.calculus == Calculus::Stratonovich;
+
+    match noise.scheme {
+        SdeScheme::PostStepSplitting => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "post-step splitting is applied by the solver loop, not sde_step_inplace",
+            ));
+        }
+
+        SdeScheme::EulerMaruyama => {
+            // Itô drift: f for Itô, f + c for Stratonovich.
+            let sign = if stratonovich { 1.0 } else { 0.0 };
+            corrected_drift(dynamics, t, kind, x, sign, &mut ctx.corr, &mut ctx.drift);
+            diffusion_increment(kind, x, &ctx.dw, &mut ctx.diff);
+            for i in 0..d {
+                out[i] = x[i] + ctx.drift[i] * dt + ctx.diff[i];
+            }
+        }
+
+        SdeScheme::Milstein => {
+            if kind.is_simplex_centred() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "Milstein needs diagonal noise; use EulerMaruyama or StochasticHeun for simplex-centred kinds",
+                ));
+            }
+
+            // Diagonal noise: ½ b_i b_i' = c_i, so the Milstein term is c_i (ΔW_i² - dt).
+            dynamics.reaction_at(t, x, &mut ctx.drift);
+            diffusion_increment(kind, x, &ctx.dw, &mut ctx.diff);
+            ito_correction(kind, x, &mut ctx.corr);
+            for i in 0..d {
+                let dw2 = ctx.dw[i] * ctx.dw[i];
+                let levy = if stratonovich { dw2 } else { dw2 - dt };
+                out[i] = x[i] + ctx.drift[i] * dt + ctx.diff[i] + ctx.corr[i] * levy;
+            }
+        }
+
+        SdeScheme::StochasticHeun => {
+            // Stratonovich drift: f for Stratonovich, f - c for Itô.
+            let sign = if stratonovich { 0.0 } else { -1.0 };
+
+            corrected_drift(dynamics, t, kind, x, sign, &mut ctx.corr, &mut ctx.drift);
+            diffusion_increment(kind, x, &ctx.dw, &mut ctx.diff);
+            for i in 0..d {
+                ctx.pred[i] = x[i] + ctx.drift[i] * dt + ctx.diff[i];
+            }
+            clamp_non_negative(&mut ctx.pred);
+
+            corrected_drift(
+                dynamics,
+                t + dt,
+                kind,
+                &ctx.pred,
+                sign,
+                &mut ctx.corr,
+                &mut ctx.drift_pred,
+            );
+            diffusion_increment(kind, &ctx.pred, &ctx.dw, &mut ctx.diff_pred);
+            for i in 0..d {
+                out[i] = x[i]
+                    + 0.5 * (ctx.drift[i] + ctx.drift_pred[i]) * dt
+                    + 0.5 * (ctx.diff[i] + ctx.diff_pred[i]);
+            }
+        }
+    }
+
+    clamp_non_negative(out);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// Column `k` of `G(x)`, obtained as `G(x) e_k`.
+    fn diffusion_column(kind: NoiseKind, x: &[f64], k: usize) -> Vec<f64> {
+        let mut e = vec![0.0; x.len()];
+        e[k] = 1.0;
+        let mut col = vec![0.0; x.len()];
+        diffusion_increment(kind, x, &e, &mut col);
+        col
+    }
+
+    #[test]
+    fn ito_correction_matches_finite_differences_of_diffusion() {
+        let x = [0.2, 0.3, 0.5];
+        let eps = 1e-6;
+
+        for kind in [
+            Noise::proportional_gaussian(0.7).kind,
+            Noise::demographic_gaussian(0.7).kind,
+            Noise::population_demographic_gaussian(0.7).kind,
+            Noise::environmental_gaussian(0.7).kind,
+        ] {
+            let mut corr = [0.0; 3];
+            ito_correction(kind, &x, &mut corr);
+
+            // c_i = ½ Σ_jk G_jk ∂_j G_ik with central differences in x_j.
+            let mut expected = [0.0; 3];
+            for j in 0..3 {
+                let mut plus = x;
+                let mut minus = x;
+                plus[j] += eps;
+                minus[j] -= eps;
+                for k in 0..3 {
+                    let g_jk = diffusion_column(kind, &x, k)[j];
+                    let col_plus = diffusion_column(kind, &plus, k);
+                    let col_minus = diffusion_column(kind, &minus, k);
+                    for i in 0..3 {
+                        let dg = (col_plus[i] - col_minus[i]) / (2.0 * eps);
+                        expected[i] += 0.5 * g_jk * dg;
+                    }
+                }
+            }
+
+            for i in 0..3 {
+                assert!(
+                    (corr[i] - expected[i]).abs() < 1e-6,
+                    "{kind:?}: {corr:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn schemes_converge_to_the_mean_of_their_calculus() {
+        // Geometric Brownian motion dn = σ n dW with n(0) = 1 at T = 1:
+        // E[n] = 1 under Itô and exp(σ²/2) under Stratonovich.
+        let zero_drift = |_: &[f64], out: &mut [f64]| out.fill(0.0);
+        let sigma: f64 = 0.5;
+        let dt = 0.01;
+        let paths = 4000;
+
+        for scheme in [
+            SdeScheme::EulerMaruyama,
+            SdeScheme::Milstein,
+            SdeScheme::StochasticHeun,
+        ] {
+            for (calculus, expected) in [
+                (Calculus::Ito, 1.0),
+                (Calculus::Stratonovich, (0.5 * sigma * sigma).exp()),
+            ] {
+                let noise = Noise::environmental_gaussian(sigma)
+                    .with_scheme(scheme)
+                    .with_calculus(calculus);
+                let mut rng = SmallRng::seed_from_u64(11);
+                let mut ctx = SdeContext::default();
+
+                let mut mean = 0.0;
+                for _ in 0..paths {
+                    let mut x = [1.0];
+                    let mut next = [0.0];
+                    for _ in 0..100 {
+                        sde_step_inplace(
+                            &zero_drift,
+                            noise,
+                            0.0,
+                            &x,
+                            dt,
+                            &mut ctx,
+                            &mut rng,
+                            &mut next,
+                        )
+                        .expect("step succeeds");
+                        x = next;
+                    }
+                    mean += x[0] / paths as f64;
+                }
+
+                assert!(
+                    (mean - expected).abs() < 0.04,
+                    "{scheme:?}/{calculus:?}: mean {mean} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn milstein_rejects_simplex_centred_noise() {
+        let zero_drift = |_: &[f64], out: &mut [f64]| out.fill(0.0);
+        let noise = Noise::demographic_gaussian(0.1).with_scheme(SdeScheme::Milstein);
+        let mut out = [0.0; 2];
+        let err = sde_step_inplace(
+            &zero_drift,
+            noise,
+            0.0,
+            &[0.5, 0.5],
+            0.1,
+            &mut SdeContext::default(),
+            &mut SmallRng::seed_from_u64(1),
+            &mut out,
+        )
+        .expect_err("centred noise is not diagonal");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
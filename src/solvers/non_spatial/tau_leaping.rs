@@ -0,0 +1,606 @@
+/*!
+Well-mixed adaptive tau-leaping.
+
+Purpose:
+    This module approximates the Gillespie jump process of `gillespie` for
+    large integer-count GLV populations. Each leap fires a Poisson number of
+    every birth and death reaction over a step `τ` chosen by the
+    Cao–Gillespie–Petzold rule, so that no propensity is expected to change
+    by more than a fraction `epsilon` of itself.
+
+Step selection:
+    A death reaction is critical when its species has fewer than
+    `critical_count` individuals. Non-critical reactions leap with
+    `τ' = min_i min(max(ε n_i / h_i, 1) / |μ_i|, max(ε n_i / h_i, 1)² / σ_i²)`,
+    where `μ_i` and `σ_i²` are the net drift and variance of species `i`
+    and `h_i` is its highest reaction order. Critical reactions fire at most
+    once per leap, after an exponential time `τ''` from their total rate.
+    When `τ'` drops below `ssa_threshold / a_0`, the solver takes
+    `ssa_steps` exact SSA events instead; this is the small-population
+    fallback. A leap that would make a count negative or overshoot
+    `carrying_capacity` is rejected and retried with `τ'` halved.
+
+Evolution contract:
+    Leaps are shortened to land exactly on every save time, so signal samples
+    form the same physical-time grid as the exact solver. Counts below the
+    mode cutoff are zeroed after every leap or event. Signal `time` records
+    the number of accepted leaps and exact events.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2};
+use rand::{Rng, RngExt};
+use rand_distr::{Distribution, Exp1, Poisson};
+use serde::{Deserialize, Serialize};
+
+use super::gillespie::{
+    GlvReactions, SsaConfig, births_allowed, fire_reaction, population_cutoff, push_samples_until,
+    round_counts,
+};
+use crate::io::signal::SignalWriter;
+use crate::solvers::dynamics::{growth_vector_or_zeros, validate_interactions};
+use crate::solvers::extinctions::ExtinctionRecord;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::{
+    SolveOutcome, StepStats, TerminationChecker, TerminationConfig, TerminationReason,
+};
+use crate::{Mode, SIGNAL_OUTPUT_FILE_SIZE, SystemState};
+
+/// Step selection and time-grid configuration for tau-leaping solves.
+///
+/// Details:
+/// - Purpose: Groups the leap-size control, the exact-SSA fallback, and the
+///   physical-time horizon and save grid of one run.
+/// - Parameters:
+///   - `epsilon`: Bound on the relative propensity change per leap.
+///   - `critical_count`: Species below this count have critical deaths.
+///   - `ssa_threshold`: Leap only if `τ' >= ssa_threshold / a_0`.
+///   - `ssa_steps`: Exact events taken per fallback burst.
+///   - `t_end`: Physical end time.
+///   - `save_time_interval`: Physical time between signal samples.
+///   - `max_steps`: Upper bound on accepted leaps plus exact events.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct TauLeapConfig {
+    pub epsilon: f64,
+    pub critical_count: f64,
+    pub ssa_threshold: f64,
+    pub ssa_steps: usize,
+    pub t_end: f64,
+    pub save_time_interval: f64,
+    pub max_steps: usize,
+}
+
+impl TauLeapConfig {
+    /// Cao–Gillespie–Petzold defaults (`ε = 0.03`, `n_c = 10`, fallback
+    /// below `10 / a_0` for 100 events) over the given time grid.
+    #[inline]
+    pub fn standard(t_end: f64, save_time_interval: f64, max_steps: usize) -> Self {
+        Self {
+            epsilon: 0.03,
+            critical_count: 10.0,
+            ssa_threshold: 10.0,
+            ssa_steps: 100,
+            t_end,
+            save_time_interval,
+            max_steps,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |message| Error::new(ErrorKind::InvalidInput, message);
+
+        if !self.epsilon.is_finite() || self.epsilon <= 0.0 || self.epsilon >= 1.0 {
+            return Err(invalid("epsilon must lie in (0, 1)"));
+        }
+        if !self.critical_count.is_finite() || self.critical_count < 0.0 {
+            return Err(invalid("critical_count must be finite and nonnegative"));
+        }
+        if !self.ssa_threshold.is_finite() || self.ssa_threshold < 0.0 {
+            return Err(invalid("ssa_threshold must be finite and nonnegative"));
+        }
+        if self.ssa_steps == 0 {
+            return Err(invalid("ssa_steps must be >= 1"));
+        }
+
+        self.grid().validate()
+    }
+
+    #[inline]
+    fn grid(&self) -> SsaConfig {
+        SsaConfig {
+            t_end: self.t_end,
+            save_time_interval: self.save_time_interval,
+            max_events: self.max_steps,
+        }
+    }
+}
+
+/// Scratch buffers for leap selection (avoid repeated allocations).
+///
+/// Details:
+/// - Purpose: Owns highest reaction orders, critical flags, and per-species
+///   leap increments for the hot loop.
+/// - Parameters:
+///   - (none): Construct with `TauLeapScratch::new`.
+struct TauLeapScratch {
+    self_interacting: Vec<bool>,
+    interacting: Vec<bool>,
+    critical: Vec<bool>,
+    delta: Vec<f64>,
+}
+
+impl TauLeapScratch {
+    fn new(interaction_matrix: &Array2<f64>) -> Self {
+        let d = interaction_matrix.nrows();
+        let interacting = (0..d)
+            .map(|i| (0..d).any(|j| interaction_matrix[(i, j)] != 0.0))
+            .collect();
+        Self {
+            self_interacting: (0..d).map(|i| interaction_matrix[(i, i)] != 0.0).collect(),
+            interacting,
+            critical: vec![false; d],
+            delta: vec![0.0; d],
+        }
+    }
+
+    /// Highest reaction order `h_i` of species `i` at count `n_i`.
+    #[inline]
+    fn order(&self, i: usize, n_i: f64) -> f64 {
+        if self.self_interacting[i] && n_i > 1.0 {
+            2.0 + 1.0 / (n_i - 1.0)
+        } else if self.interacting[i] {
+            2.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Non-critical leap size `τ'`, flagging critical deaths in `sc.critical`.
+#[allow(clippy::needless_range_loop)]
+fn noncritical_tau(
+    reactions: &GlvReactions,
+    n: &[f64],
+    config: &TauLeapConfig,
+    sc: &mut TauLeapScratch,
+) -> f64 {
+    let mut tau = f64::INFINITY;
+    for i in 0..n.len() {
+        sc.critical[i] = reactions.death[i] > 0.0 && n[i] < config.critical_count;
+        if n[i] <= 0.0 {
+            continue;
+        }
+
+        let death = if sc.critical[i] {
+            0.0
+        } else {
+            reactions.death[i]
+        };
+        let mu = reactions.birth[i] - death;
+        let var = reactions.birth[i] + death;
+        let bound = (config.epsilon * n[i] / sc.order(i, n[i])).max(1.0);
+        if mu != 0.0 {
+            tau = tau.min(bound / mu.abs());
+        }
+        if var > 0.0 {
+            tau = tau.min(bound * bound / var);
+        }
+    }
+    tau
+}
+
+/// Draw a Poisson count with mean `lambda` (zero for a nonpositive mean).
+#[inline]
+fn poisson(lambda: f64, rng: &mut impl Rng) -> f64 {
+    if lambda > 0.0 {
+        Poisson::new(lambda)
+            .map(|dist| dist.sample(rng))
+            .unwrap_or(0.0)
+    } else {
+        0.0
+    }
+}
+
+/// Simulate a single well-mixed GLV trajectory with adaptive tau-leaping.
+///
+/// Details:
+/// - Purpose: Runs one integer-count `Mode::Population` trajectory up to
+///   `config.t_end` and writes signal samples on a regular physical-time
+///   grid. Initial counts are rounded.
+/// - Parameters:
+///   - `gs_i`: Initial population state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `config`: Leap control, SSA fallback, and time grid.
+///   - `output_path`: Directory for signal JSON output.
+///   - `progress_counter`: Optional shared progress counter (steps).
+///
+/// Draws come from a fresh entropy seed; pass an explicit `RngSeed` to
+/// `solve_glv_tau_leap_with_termination` for reproducible runs.
+pub fn solve_glv_tau_leap(
+    gs_i: SystemState<f64>,                 // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: TauLeapConfig,                  // leap control and time grid
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+) -> Result<SystemState<f64>> {
+    Ok(solve_glv_tau_leap_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        config,
+        RngSeed::from_entropy(),
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Simulate a single well-mixed GLV trajectory with tau-leaping and explicit termination.
+///
+/// Details:
+/// - Purpose: Same as `solve_glv_tau_leap`, returning `SolveOutcome` whose
+///   `step_stats` count accepted steps (leaps plus exact events), rejected
+///   leaps, and the final physical time. Termination checks run on
+///   `check_interval` multiples of accepted steps. All draws come from
+///   `seed`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_glv_tau_leap_with_termination(
+    mut gs_i: SystemState<f64>,             // initial state (consumed)
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    config: TauLeapConfig,                  // leap control and time grid
+    seed: RngSeed,                          // random stream
+    output_path: &Path,                     // signal output target
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<SolveOutcome> {
+    config.validate()?;
+    round_counts(&mut gs_i)?;
+    validate_interactions(interaction_matrix.dim(), growth_vector, gs_i.state.len())?;
+
+    let grid = config.grid();
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let mut reactions = GlvReactions::new(interaction_matrix, &growth_vector);
+    let mut sc = TauLeapScratch::new(interaction_matrix);
+    let cutoff = population_cutoff(&gs_i.mode);
+    let d = gs_i.state.len();
+
+    let mut gs_curr = gs_i;
+    let mut signal_writer = SignalWriter::new(
+        output_path,
+        gs_curr.mode.clone(),
+        SIGNAL_OUTPUT_FILE_SIZE,
+        gs_curr.state.len(),
+    )?;
+    signal_writer.push(&gs_curr)?; // t=0 always saved
+
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let mut rng = seed.rng();
+    let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut extinctions = Vec::new();
+    reactions.reset(gs_curr.state.as_slice().expect("state is contiguous"));
+
+    // Main loop: leap size -> leap or exact event -> snapshot -> termination.
+    let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
+    let mut stats = StepStats::default();
+    let mut t = 0.0;
+    let mut save_index = 1usize;
+    let mut ssa_remaining = 0usize;
+    let mut termination_reason = TerminationReason::MaxSteps;
+    while t < config.t_end && stats.accepted_steps < config.max_steps {
+        let n = gs_curr.state.as_slice().expect("state is contiguous");
+        let total = reactions.update(n, births_allowed(&gs_curr.mode, gs_curr.mass));
+        let t_save = (save_index as f64 * config.save_time_interval).min(config.t_end);
+
+        let mut tau_leap = noncritical_tau(&reactions, n, &config, &mut sc);
+        if ssa_remaining == 0 && total > 0.0 && tau_leap < config.ssa_threshold / total {
+            ssa_remaining = config.ssa_steps;
+        }
+
+        // Leap attempt: Poisson firings over τ, halving τ' on rejection.
+        let mut leapt = false;
+        if ssa_remaining == 0 && total > 0.0 {
+            let critical_total: f64 = (0..d)
+                .filter(|&i| sc.critical[i])
+                .map(|i| reactions.death[i])
+                .sum();
+            let tau_critical = if critical_total > 0.0 {
+                let wait: f64 = Exp1.sample(&mut rng);
+                wait / critical_total
+            } else {
+                f64::INFINITY
+            };
+
+            loop {
+                let tau = tau_leap.min(tau_critical).min(t_save - t);
+                let mut valid = true;
+                let mut mass = gs_curr.mass;
+                for i in 0..d {
+                    let death = if sc.critical[i] {
+                        0.0
+                    } else {
+                        reactions.death[i]
+                    };
+                    let delta = poisson(reactions.birth[i] * tau, &mut rng)
+                        - poisson(death * tau, &mut rng);
+                    sc.delta[i] = delta;
+                    mass += delta;
+                    valid &= gs_curr.state[i] + delta >= 0.0;
+                }
+
+                // At most one critical death when it falls inside this leap.
+                if tau == tau_critical {
+                    let mut target = rng.random::<f64>() * critical_total;
+                    if let Some(k) = (0..d).filter(|&i| sc.critical[i]).find(|&i| {
+                        target -= reactions.death[i];
+                        target < 0.0
+                    }) {
+                        sc.delta[k] -= 1.0;
+                        mass -= 1.0;
+                        valid &= gs_curr.state[k] + sc.delta[k] >= 0.0;
+                    }
+                }
+
+                if let Mode::Population {
+                    carrying_capacity: Some(capacity),
+                    ..
+                } = gs_curr.mode
+                {
+                    valid &= mass <= capacity.max(gs_curr.mass);
+                }
+
+                if valid {
+                    t += tau;
+                    for i in 0..d {
+                        let before = gs_curr.state[i];
+                        let value = before + sc.delta[i];
+                        gs_curr.state[i] = if value < cutoff { 0.0 } else { value };
+                        if before > 0.0 && gs_curr.state[i] == 0.0 {
+                            extinctions.push(ExtinctionRecord {
+                                step: stats.accepted_steps + 1,
+                                time: start_physical_time + t,
+                                species: i,
+                                abundance: before,
+                            });
+                        }
+                    }
+                    gs_curr.mass = gs_curr.state.sum();
+                    reactions.reset(gs_curr.state.as_slice().expect("state is contiguous"));
+                    leapt = true;
+                    break;
+                }
+
+                stats.rejected_steps += 1;
+                tau_leap *= 0.5;
+                if tau_leap < config.ssa_threshold / total {
+                    ssa_remaining = config.ssa_steps;
+                    break;
+                }
+            }
+        }
+
+        if leapt {
+            if t >= t_save {
+                t = t_save;
+                gs_curr.physical_time = start_physical_time + t;
+                signal_writer.push(&gs_curr)?;
+                save_index += 1;
+            }
+        } else {
+            // Exact SSA event, also used when no reaction can fire.
+            ssa_remaining = ssa_remaining.saturating_sub(1);
+            let t_next = if total > 0.0 {
+                let wait: f64 = Exp1.sample(&mut rng);
+                t + wait / total
+            } else {
+                f64::INFINITY
+            };
+            save_index = push_samples_until(
+                &mut signal_writer,
+                &gs_curr,
+                &grid,
+                start_physical_time,
+                save_index,
+                t_next,
+            )?;
+            if t_next >= config.t_end {
+                t = config.t_end;
+                break;
+            }
+
+            t = t_next;
+            let target = rng.random::<f64>() * total;
+            if let Some((k, delta)) = fire_reaction(&reactions, &mut gs_curr, target, cutoff) {
+                reactions.shift(k, delta);
+
+                // The fired species held `-delta` before it dropped to zero.
+                if delta < 0.0 && gs_curr.state[k] == 0.0 {
+                    extinctions.push(ExtinctionRecord {
+                        step: stats.accepted_steps + 1,
+                        time: start_physical_time + t,
+                        species: k,
+                        abundance: -delta,
+                    });
+                }
+            }
+        }
+
+        stats.accepted_steps += 1;
+        gs_curr.time = start_time + stats.accepted_steps;
+        gs_curr.physical_time = start_physical_time + t;
+        let step = stats.accepted_steps;
+
+        if let Some(counter) = progress_counter {
+            counter.store(step, Ordering::Relaxed);
+        }
+
+        if let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            signal_writer.push(&gs_curr)?;
+            break;
+        }
+    }
+    gs_curr.physical_time = start_physical_time + t;
+    stats.final_time = gs_curr.physical_time;
+
+    let signal_stats = signal_writer.finish()?;
+
+    Ok(SolveOutcome {
+        final_state: gs_curr,
+        steps_run: stats.accepted_steps,
+        reason: termination_reason,
+        signal_stats,
+        space_stats: None,
+        step_stats: Some(stats),
+        events: Vec::new(),
+        extinctions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_non_spatial_tau_leaping_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    fn population(values: Array1<f64>) -> SystemState<f64> {
+        SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            values,
+            None,
+        )
+    }
+
+    #[test]
+    fn large_logistic_population_leaps_to_carrying_level() {
+        // K = g / |V| = 1e5 individuals; exact SSA would need millions of events.
+        let v = array![[-1e-5]];
+        let g = array![1.0];
+        let output_path = temp_output_dir("logistic");
+
+        let outcome = solve_glv_tau_leap_with_termination(
+            population(array![1e4]),
+            &v,
+            Some(&g),
+            TauLeapConfig::standard(20.0, 1.0, 1_000_000),
+            RngSeed::new(1),
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        let stats = outcome.step_stats.expect("tau-leap stats");
+        let n = outcome.final_state.state[0];
+        assert!((n - 1e5).abs() < 5e3, "final count {n}");
+        assert_eq!(n.fract(), 0.0);
+        assert_eq!(stats.final_time, 20.0);
+        assert_eq!(outcome.signal_stats.samples, 21);
+        assert!(outcome.steps_run < 10_000);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn small_populations_fall_back_to_exact_events() {
+        let v = Array2::zeros((1, 1));
+        let g = array![-1.0];
+        let output_path = temp_output_dir("fallback");
+
+        let outcome = solve_glv_tau_leap_with_termination(
+            population(array![20.0]),
+            &v,
+            Some(&g),
+            TauLeapConfig::standard(100.0, 10.0, 10_000),
+            RngSeed::new(1),
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        // Every death is an exact event; counts never go negative.
+        assert_eq!(outcome.final_state.state[0], 0.0);
+        assert_eq!(outcome.steps_run, 20);
+        assert_eq!(outcome.signal_stats.samples, 11);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn leaps_respect_carrying_capacity_and_reject_bad_config() {
+        let v = Array2::zeros((2, 2));
+        let g = array![1.0, 1.0];
+        let output_path = temp_output_dir("capacity");
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: Some(5e4),
+            },
+            0,
+            array![1e3, 1e3],
+            None,
+        );
+
+        let out = solve_glv_tau_leap(
+            gs,
+            &v,
+            Some(&g),
+            TauLeapConfig::standard(10.0, 1.0, 1_000_000),
+            &output_path,
+            None,
+        )
+        .expect("solve succeeds");
+        assert_eq!(out.state.sum(), 5e4);
+
+        let mut config = TauLeapConfig::standard(1.0, 1.0, 10);
+        config.epsilon = 0.0;
+        let err = solve_glv_tau_leap(
+            population(array![1.0, 1.0]),
+            &v,
+            Some(&g),
+            config,
+            &output_path,
+            None,
+        )
+        .err()
+        .expect("epsilon rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let err = solve_glv_tau_leap(
+            population(array![1.0, 1.0, 1.0]),
+            &v,
+            Some(&g),
+            TauLeapConfig::standard(1.0, 1.0, 10),
+            &output_path,
+            None,
+        )
+        .err()
+        .expect("shape mismatch rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
@@ -0,0 +1,76 @@
+/*!
+Seeded random streams for stochastic solvers.
+
+Purpose:
+    Every stochastic solver draws from a ChaCha8 generator built from an
+    explicit `RngSeed`, so a recorded seed regenerates a trajectory
+    bit-for-bit. A seed pairs a 64-bit key with a ChaCha stream index;
+    replicates share the key and use distinct streams, which gives
+    independent, non-overlapping sequences without hashing seeds together.
+*/
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// Generator type used by every stochastic solver.
+pub type SolverRng = ChaCha8Rng;
+
+/// Key and stream index identifying one reproducible random sequence.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RngSeed {
+    pub seed: u64,
+    pub stream: u64,
+}
+
+impl RngSeed {
+    /// Seed on stream zero.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self { seed, stream: 0 }
+    }
+
+    /// Draw a fresh key from the OS entropy source.
+    ///
+    /// The drawn value is returned so callers can record it and replay the
+    /// run later.
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Independent stream for replicate `k` under the same key.
+    #[inline]
+    pub fn replicate(self, k: u64) -> Self {
+        Self {
+            seed: self.seed,
+            stream: k,
+        }
+    }
+
+    /// Build the generator for this key and stream.
+    pub fn rng(self) -> SolverRng {
+        let mut rng = SolverRng::seed_from_u64(self.seed);
+        rng.set_stream(self.stream);
+        rng
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngExt;
+
+    #[test]
+    fn same_seed_replays_and_replicates_diverge() {
+        let draw = |seed: RngSeed| {
+            let mut rng = seed.rng();
+            (0..8).map(|_| rng.random::<u64>()).collect::<Vec<_>>()
+        };
+
+        let base = RngSeed::new(42);
+        assert_eq!(draw(base), draw(base));
+        assert_eq!(draw(base), draw(base.replicate(0)));
+        assert_ne!(draw(base.replicate(0)), draw(base.replicate(1)));
+        assert_ne!(draw(base), draw(RngSeed::new(43)));
+    }
+}
@@ -4,12 +4,14 @@
 //! This module implements deterministic reaction-diffusion dynamics for a
 //! species-last spatial field, `space[x0, x1, ..., x{k-1}, i]`.
 //!
-//! The local reaction is GLV population dynamics and diffusion is a
+//! The local reaction is any per-cell `Dynamics` term (built-in GLV population
+//! or local replicator dynamics, or a user model) and diffusion is a
 //! finite-difference Laplacian across the spatial axes.
 //!
 //! Evolution contract:
-//! One solver step follows this sequence: RK4 raw reaction-diffusion step,
-//! spatial sanitize/global-state refresh, then snapshot check.
+//! One solver step follows this sequence: integrator raw reaction-diffusion
+//! step (RK4 by default), spatial sanitize/global-state refresh, then snapshot
+//! check.
 
 use std::io::{Error, ErrorKind, Result};
 use std::path::Path;
@@ -19,6 +21,17 @@ use ndarray::{Array1, Array2, ArrayD};
 
 use crate::io::signal::SignalWriter;
 use crate::io::space::SpaceWriter;
+use crate::solvers::dynamics::{
+    Dynamics, GlvPopulation, Immigration, Replicator, ReplicatorMutator, growth_vector_or_zeros,
+    validate_immigration, validate_mutation_matrix,
+};
+use crate::solvers::events::{EventQueue, EventRecord, EventSchedule, introduce_inplace};
+use crate::solvers::extinctions::record_extinctions;
+use crate::solvers::forcing::{Forced, Forcing};
+use crate::solvers::functional_response::{FunctionalResponse, HollingGlv};
+use crate::solvers::higher_order::{HigherOrder, InteractionTensor};
+use crate::solvers::integrator::{Integrator, Rk4, VectorField};
+use crate::solvers::sparse::{CsrMatrix, SparseInteractions};
 use crate::solvers::termination::{
     SolveOutcome, TerminationChecker, TerminationConfig, TerminationReason,
 };
@@ -73,40 +86,6 @@ impl Diffusion {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Dynamics {
-    GlvPopulation,
-    LocalReplicatorFrequency,
-}
-
-/// Scratch buffers for spatial RK4 (avoid repeated allocations).
-///
-/// Details:
-/// - Purpose: Owns stage derivatives and temporary spatial storage for the hot
-///   integration loop.
-/// - Parameters:
-///   - (none): Construct with `SpatialRk4Scratch::new`.
-struct SpatialRk4Scratch {
-    k1: ArrayD<f64>,
-    k2: ArrayD<f64>,
-    k3: ArrayD<f64>,
-    k4: ArrayD<f64>,
-    tmp: ArrayD<f64>,
-}
-
-impl SpatialRk4Scratch {
-    #[inline]
-    fn new(shape: &[usize]) -> Self {
-        Self {
-            k1: ArrayD::zeros(shape),
-            k2: ArrayD::zeros(shape),
-            k3: ArrayD::zeros(shape),
-            k4: ArrayD::zeros(shape),
-            tmp: ArrayD::zeros(shape),
-        }
-    }
-}
-
 /// Cached shape facts for species-last spatial arrays.
 ///
 /// Details:
@@ -164,71 +143,39 @@ impl SpatialLayout {
     }
 }
 
-/// Compute the spatial GLV reaction-diffusion RHS in-place.
+/// Compute the spatial reaction-diffusion RHS in-place.
 ///
 /// Details:
 /// - Purpose: Evaluates
-///       out_i(x) = u_i(x) * (g_i + Σ_j V_ij u_j(x)) + D_i Δu_i(x)
+///   `out_i(x) = reaction_i(t, u(x)) + D_i Δu_i(x)`
 ///   without allocating.
 /// - Parameters:
-///   - `space`: Current species-last spatial field.
-///   - `growth_vector`: Growth vector `g`.
-///   - `interaction_matrix`: Interaction matrix `V`.
+///   - `t`: Stage time passed to the reaction term.
+///   - `u`: Current species-last spatial field, flattened in memory order.
+///   - `dynamics`: Per-cell reaction term.
 ///   - `diffusion`: Diffusion configuration.
 ///   - `layout`: Cached species-last shape/stride facts.
-///   - `out`: Destination derivative.
+///   - `y`: Destination derivative, flattened in memory order.
 #[inline]
 fn rhs_inplace(
-    space: &ArrayD<f64>,
-    growth_vector: &Array1<f64>,
-    interaction_matrix: &Array2<f64>,
+    t: f64,
+    u: &[f64],
+    dynamics: &dyn Dynamics,
     diffusion: &Diffusion,
     layout: &SpatialLayout,
-    dynamics: Dynamics,
-    out: &mut ArrayD<f64>,
-) -> Result<()> {
-    let u = space.as_slice_memory_order().ok_or_else(|| {
-        Error::new(
-            ErrorKind::InvalidInput,
-            "spatial state must use standard contiguous memory layout",
-        )
-    })?;
-    let y = out.as_slice_memory_order_mut().ok_or_else(|| {
-        Error::new(
-            ErrorKind::InvalidInput,
-            "spatial output must use standard contiguous memory layout",
-        )
-    })?;
-
+    y: &mut [f64],
+) {
     let d = layout.num_species;
 
     for cell in 0..layout.num_cells {
         let base = cell * d;
 
-        let upsilon = match dynamics {
-            Dynamics::GlvPopulation => 0.0,
-            Dynamics::LocalReplicatorFrequency => {
-                let mut acc = 0.0;
-                for i in 0..d {
-                    let mut interaction = 0.0;
-                    for j in 0..d {
-                        interaction += interaction_matrix[(i, j)] * u[base + j];
-                    }
-                    acc += u[base + i] * (growth_vector[i] + interaction);
-                }
-                acc
-            }
-        };
+        dynamics.reaction_at(t, &u[base..base + d], &mut y[base..base + d]);
 
         for species in 0..d {
             let center_idx = base + species;
             let center = u[center_idx];
 
-            let mut interaction = 0.0;
-            for j in 0..d {
-                interaction += interaction_matrix[(species, j)] * u[base + j];
-            }
-
             let mut laplacian = 0.0;
             for axis in 0..layout.spatial_ndim {
                 let axis_len = layout.shape[axis];
@@ -257,161 +204,39 @@ fn rhs_inplace(
                 laplacian += (u[plus_idx] + u[minus_idx] - 2.0 * center) * inv_dx2;
             }
 
-            let reaction = match dynamics {
-                Dynamics::GlvPopulation => center * (growth_vector[species] + interaction),
-                Dynamics::LocalReplicatorFrequency => {
-                    center * (growth_vector[species] + interaction - upsilon)
-                }
-            };
-
-            y[center_idx] = reaction + diffusion.coefficients[species] * laplacian;
+            y[center_idx] += diffusion.coefficients[species] * laplacian;
         }
     }
-
-    Ok(())
 }
 
-/// One RK4 reaction-diffusion step writing into `out`.
+/// Spatial reaction-diffusion vector field for the shared integrators.
 ///
 /// Details:
-/// - Purpose: Advances one raw deterministic spatial step without enforcing
-///   mode-specific state invariants.
+/// - Purpose: Binds the per-cell dynamics, diffusion, and layout to
+///   `rhs_inplace` over the flattened species-last field. No Jacobian is
+///   provided, so implicit integrators are rejected.
 /// - Parameters:
-///   - `space`: Current species-last spatial field.
-///   - `growth_vector`: Growth vector `g`.
-///   - `interaction_matrix`: Interaction matrix `V`.
-///   - `diffusion`: Diffusion configuration.
-///   - `dt`: Step size.
-///   - `layout`: Cached species-last shape/stride facts.
-///   - `sc`: Reusable RK4 scratch storage.
-///   - `out`: Raw next-space destination.
-#[inline]
-fn rk4_step_inplace_raw(
-    space: &ArrayD<f64>,
-    growth_vector: &Array1<f64>,
-    interaction_matrix: &Array2<f64>,
-    diffusion: &Diffusion,
-    dt: f64,
-    layout: &SpatialLayout,
-    dynamics: Dynamics,
-    sc: &mut SpatialRk4Scratch,
-    out: &mut ArrayD<f64>,
-) -> Result<()> {
-    let u = space.as_slice_memory_order().ok_or_else(|| {
-        Error::new(
-            ErrorKind::InvalidInput,
-            "spatial state must use standard contiguous memory layout",
-        )
-    })?;
-    let half_dt = 0.5 * dt;
-    let dt_over_6 = dt / 6.0;
-
-    rhs_inplace(
-        space,
-        growth_vector,
-        interaction_matrix,
-        diffusion,
-        layout,
-        dynamics,
-        &mut sc.k1,
-    )?;
-
-    {
-        let k1 = sc
-            .k1
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let tmp = sc
-            .tmp
-            .as_slice_memory_order_mut()
-            .expect("scratch is contiguous");
-        for i in 0..u.len() {
-            tmp[i] = u[i] + half_dt * k1[i];
-        }
-    }
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
-        interaction_matrix,
-        diffusion,
-        layout,
-        dynamics,
-        &mut sc.k2,
-    )?;
-
-    {
-        let k2 = sc
-            .k2
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let tmp = sc
-            .tmp
-            .as_slice_memory_order_mut()
-            .expect("scratch is contiguous");
-        for i in 0..u.len() {
-            tmp[i] = u[i] + half_dt * k2[i];
-        }
-    }
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
-        interaction_matrix,
-        diffusion,
-        layout,
-        dynamics,
-        &mut sc.k3,
-    )?;
+///   - `time`: Step start time, used by integrators that call `eval`
+///     rather than `eval_at`.
+///   - (others): Construct with a struct literal inside `solve_impl`.
+struct SpatialField<'a> {
+    dynamics: &'a dyn Dynamics,
+    diffusion: &'a Diffusion,
+    layout: &'a SpatialLayout,
+    time: f64,
+}
 
-    {
-        let k3 = sc
-            .k3
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let tmp = sc
-            .tmp
-            .as_slice_memory_order_mut()
-            .expect("scratch is contiguous");
-        for i in 0..u.len() {
-            tmp[i] = u[i] + dt * k3[i];
-        }
+impl VectorField for SpatialField<'_> {
+    #[inline]
+    fn eval(&mut self, u: &[f64], out: &mut [f64]) -> Result<()> {
+        self.eval_at(self.time, u, out)
     }
-    rhs_inplace(
-        &sc.tmp,
-        growth_vector,
-        interaction_matrix,
-        diffusion,
-        layout,
-        dynamics,
-        &mut sc.k4,
-    )?;
-
-    {
-        let k1 = sc
-            .k1
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let k2 = sc
-            .k2
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let k3 = sc
-            .k3
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let k4 = sc
-            .k4
-            .as_slice_memory_order()
-            .expect("scratch is contiguous");
-        let y = out
-            .as_slice_memory_order_mut()
-            .expect("output is contiguous");
 
-        for i in 0..u.len() {
-            y[i] = u[i] + dt_over_6 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
-        }
+    #[inline]
+    fn eval_at(&mut self, t: f64, u: &[f64], out: &mut [f64]) -> Result<()> {
+        rhs_inplace(t, u, self.dynamics, self.diffusion, self.layout, out);
+        Ok(())
     }
-
-    Ok(())
 }
 
 /// Clamp spatial values, apply optional global capacity, and refresh `state`.
@@ -565,31 +390,70 @@ fn sanitize_local_simplex_space_and_refresh_state(
     Ok(())
 }
 
-fn validate_inputs(
-    layout: &SpatialLayout,
+/// Dispatch spatial sanitize on the state's representation mode.
+#[inline]
+fn sanitize_by_mode(gs: &mut SystemState<f64>, layout: &SpatialLayout) -> Result<()> {
+    match gs.mode {
+        Mode::Population { .. } => sanitize_space_and_refresh_state(gs, layout),
+        Mode::Frequency { .. } => sanitize_local_simplex_space_and_refresh_state(gs, layout),
+    }
+}
+
+/// Check built-in `V`/`g` shapes against the species axis of `gs.space`.
+///
+/// Details:
+/// - Purpose: Validates built-in interaction inputs before they are wrapped
+///   in a `Dynamics` value. A missing spatial field is reported later by
+///   `solve_dynamics`.
+fn validate_interactions(
+    gs: &SystemState<f64>,
     interaction_matrix: &Array2<f64>,
     growth_vector: Option<&Array1<f64>>,
-    diffusion: &Diffusion,
-    dt: f64,
-    save_signal_interval: usize,
-    save_space_interval: usize,
 ) -> Result<()> {
-    let d = layout.num_species;
+    validate_interaction_shape(gs, interaction_matrix.dim(), growth_vector)
+}
+
+/// `validate_interactions` for any `V` representation, given its shape.
+fn validate_interaction_shape(
+    gs: &SystemState<f64>,
+    (nrows, ncols): (usize, usize),
+    growth_vector: Option<&Array1<f64>>,
+) -> Result<()> {
+    let Some(d) = gs
+        .space
+        .as_ref()
+        .and_then(|space| space.shape().last().copied())
+    else {
+        return Ok(());
+    };
 
-    if interaction_matrix.nrows() != d || interaction_matrix.ncols() != d {
+    if nrows != d || ncols != d {
         return Err(Error::new(
             ErrorKind::InvalidInput,
             "interaction_matrix must be square with size matching the species axis",
         ));
     }
-    if let Some(g) = growth_vector {
-        if g.len() != d {
-            return Err(Error::new(
-                ErrorKind::InvalidInput,
-                "growth_vector length must match the species axis",
-            ));
-        }
+    if let Some(g) = growth_vector
+        && g.len() != d
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "growth_vector length must match the species axis",
+        ));
     }
+
+    Ok(())
+}
+
+fn validate_inputs(
+    layout: &SpatialLayout,
+    diffusion: &Diffusion,
+    dt: f64,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+) -> Result<()> {
+    let d = layout.num_species;
+
     if diffusion.coefficients.len() != d {
         return Err(Error::new(
             ErrorKind::InvalidInput,
@@ -662,16 +526,19 @@ fn validate_inputs(
     Ok(())
 }
 
-/// Integrate a single spatial trajectory and persist split signal/space output.
+/// Integrate a single spatial trajectory of user-defined dynamics.
 ///
 /// Details:
-/// - Purpose: Runs one spatial trajectory and writes aggregate signal and full
-///   spatial snapshots to independent output streams.
+/// - Purpose: Runs one reaction-diffusion trajectory where `dynamics` is
+///   evaluated per cell, and writes aggregate signal and full spatial
+///   snapshots to independent output streams. Invariants follow
+///   `gs_i.mode`: `Mode::Population` fields get cutoff and carrying capacity,
+///   `Mode::Frequency` fields are renormalized onto the simplex per cell.
 /// - Parameters:
-///   - `gs_i`: Initial spatial population state consumed by the solver.
-///   - `interaction_matrix`: Square interaction matrix `V`.
-///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `gs_i`: Initial spatial state consumed by the solver.
+///   - `dynamics`: Per-cell reaction term.
 ///   - `diffusion`: Per-species diffusion coefficients and grid metadata.
+///   - `integrator`: Explicit raw step scheme.
 ///   - `dt`: Step size.
 ///   - `num_steps`: Number of integration steps.
 ///   - `save_signal_interval`: Save aggregate state every Nth step; `t = 0`
@@ -680,19 +547,63 @@ fn validate_inputs(
 ///     `t = 0` is always saved.
 ///   - `output_path`: Directory for split signal/space JSON output.
 ///   - `progress_counter`: Optional shared progress counter.
-fn solve_impl(
+///   - `termination`: Explicit termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_dynamics(
+    gs_i: SystemState<f64>,
+    dynamics: &dyn Dynamics,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    solve_dynamics_with_events(
+        gs_i,
+        dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+        &EventSchedule::new(),
+    )
+}
+
+/// Integrate a single spatial trajectory with scheduled species introductions.
+///
+/// Details:
+/// - Purpose: Same as `solve_dynamics`, but applies `events` in order after
+///   each step's snapshots (see `solvers::events`). An event without cells
+///   introduces into every cell; otherwise only into the listed cells.
+///   Applied events are marked in the signal stream and returned in
+///   `SolveOutcome.events`. Termination checks start once every event has
+///   fired.
+/// - Parameters:
+///   - `events`: Ordered introductions; cells index the spatial axes.
+///   - (others): As in `solve_dynamics`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_dynamics_with_events(
     mut gs_i: SystemState<f64>,
-    interaction_matrix: &Array2<f64>,
-    growth_vector: Option<&Array1<f64>>,
+    dynamics: &dyn Dynamics,
     diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
     dt: f64,
     num_steps: usize,
     save_signal_interval: usize,
     save_space_interval: usize,
     output_path: &Path,
     progress_counter: Option<&AtomicUsize>,
-    dynamics: Dynamics,
     termination: TerminationConfig,
+    events: &EventSchedule,
 ) -> Result<SolveOutcome> {
     let Some(space) = gs_i.space.take() else {
         return Err(Error::new(
@@ -705,8 +616,6 @@ fn solve_impl(
 
     validate_inputs(
         &layout,
-        interaction_matrix,
-        growth_vector,
         diffusion,
         dt,
         save_signal_interval,
@@ -714,20 +623,11 @@ fn solve_impl(
     )?;
 
     let d = layout.num_species;
-    let growth_vector_owned = growth_vector
-        .map(|x| x.to_owned())
-        .unwrap_or_else(|| Array1::zeros(d));
-
     if gs_i.state.len() != d {
         gs_i.state = Array1::zeros(d);
     }
     gs_i.space = Some(space);
-    match dynamics {
-        Dynamics::GlvPopulation => sanitize_space_and_refresh_state(&mut gs_i, &layout)?,
-        Dynamics::LocalReplicatorFrequency => {
-            sanitize_local_simplex_space_and_refresh_state(&mut gs_i, &layout)?
-        }
-    }
+    sanitize_by_mode(&mut gs_i, &layout)?;
 
     let mut gs_curr = gs_i;
     let space_len = gs_curr.space.as_ref().map(|space| space.len()).unwrap_or(0);
@@ -759,39 +659,58 @@ fn solve_impl(
         Array1::zeros(d),
         Some(ArrayD::zeros(shape.clone())),
     );
-    let mut sc = SpatialRk4Scratch::new(&shape);
+    let mut field = SpatialField {
+        dynamics,
+        diffusion,
+        layout: &layout,
+        time: 0.0,
+    };
     let mut next_space = ArrayD::zeros(shape);
     let mut termination_checker = TerminationChecker::new(termination)?;
+    let mut event_queue = EventQueue::new(
+        events,
+        &gs_curr,
+        d,
+        Some(&layout.shape[..layout.spatial_ndim]),
+    )?;
+    let mut event_log = Vec::new();
+    let mut extinctions = Vec::new();
+    let frequency = matches!(gs_curr.mode, Mode::Frequency { .. });
 
     let start_time = gs_curr.time;
+    let start_physical_time = gs_curr.physical_time;
     let mut steps_run = 0usize;
     let mut termination_reason = TerminationReason::MaxSteps;
     for step in 1..=num_steps {
         let curr_space = gs_curr.space.as_ref().expect("space initialized");
-        rk4_step_inplace_raw(
-            curr_space,
-            &growth_vector_owned,
-            interaction_matrix,
-            diffusion,
-            dt,
-            &layout,
-            dynamics,
-            &mut sc,
-            &mut next_space,
-        )?;
+        let u = curr_space.as_slice_memory_order().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "spatial state must use standard contiguous memory layout",
+            )
+        })?;
+        let y = next_space
+            .as_slice_memory_order_mut()
+            .expect("output is contiguous");
+        let t = gs_curr.physical_time;
+        field.time = t;
+        integrator.step_at(&mut field, t, u, dt, y)?;
 
         gs_next.space = Some(next_space);
-        match dynamics {
-            Dynamics::GlvPopulation => sanitize_space_and_refresh_state(&mut gs_next, &layout)?,
-            Dynamics::LocalReplicatorFrequency => {
-                sanitize_local_simplex_space_and_refresh_state(&mut gs_next, &layout)?
-            }
-        }
+        sanitize_by_mode(&mut gs_next, &layout)?;
         gs_next.time = start_time + step;
+        gs_next.physical_time = start_physical_time + step as f64 * dt;
 
         std::mem::swap(&mut gs_curr, &mut gs_next);
         next_space = gs_next.space.take().expect("space buffer retained");
         steps_run = step;
+        record_extinctions(
+            gs_next.state.as_slice().expect("state is contiguous"),
+            gs_curr.state.as_slice().expect("state is contiguous"),
+            step,
+            gs_curr.physical_time,
+            &mut extinctions,
+        );
 
         let save_signal = step % save_signal_interval == 0;
         let save_space = step % save_space_interval == 0;
@@ -806,17 +725,59 @@ fn solve_impl(
             counter.store(step, Ordering::Relaxed);
         }
 
-        if let Some(checker) = termination_checker.as_mut() {
-            if let Some(reason) = checker.check(&gs_curr, step) {
-                termination_reason = reason;
-                if !save_signal {
-                    signal_writer.push(&gs_curr)?;
+        while let Some((index, event, cause)) = event_queue.poll(&gs_curr, step)? {
+            let before = gs_curr.state.clone();
+            let u = gs_curr
+                .space
+                .as_mut()
+                .and_then(|space| space.as_slice_memory_order_mut())
+                .expect("space is contiguous");
+            match &event.cells {
+                None => {
+                    for cell in u.chunks_exact_mut(d) {
+                        introduce_inplace(cell, event, frequency);
+                    }
                 }
-                if !save_space {
-                    space_writer.push(&gs_curr)?;
+                Some(cells) => {
+                    for cell in cells {
+                        let base: usize =
+                            cell.iter().zip(&layout.strides).map(|(i, s)| i * s).sum();
+                        introduce_inplace(&mut u[base..base + d], event, frequency);
+                    }
                 }
-                break;
             }
+            sanitize_by_mode(&mut gs_curr, &layout)?;
+            record_extinctions(
+                before.as_slice().expect("state is contiguous"),
+                gs_curr.state.as_slice().expect("state is contiguous"),
+                step,
+                gs_curr.physical_time,
+                &mut extinctions,
+            );
+            signal_writer.push_event(&gs_curr, index)?;
+            event_log.push(EventRecord {
+                index,
+                step,
+                time: gs_curr.time,
+                physical_time: gs_curr.physical_time,
+                cause,
+                introductions: event.introductions.clone(),
+                cells: event.cells.clone(),
+            });
+        }
+
+        if !event_queue.is_pending()
+            && let Some(checker) = termination_checker.as_mut()
+            && let Some(reason) = checker.check(&gs_curr, step)
+        {
+            termination_reason = reason;
+            if !save_signal {
+                signal_writer.push(&gs_curr)?;
+            }
+            if !save_space {
+                space_writer.push(&gs_curr)?;
+            }
+            break;
         }
     }
 
@@ -829,6 +790,9 @@ fn solve_impl(
         reason: termination_reason,
         signal_stats,
         space_stats: Some(space_stats),
+        step_stats: None,
+        events: event_log,
+        extinctions,
     })
 }
 
@@ -850,6 +814,7 @@ fn solve_impl(
 ///     `t = 0` is always saved.
 ///   - `output_path`: Directory for split signal/space JSON output.
 ///   - `progress_counter`: Optional shared progress counter.
+#[allow(clippy::too_many_arguments)]
 pub fn solve(
     gs_i: SystemState<f64>,
     interaction_matrix: &Array2<f64>,
@@ -879,6 +844,7 @@ pub fn solve(
 }
 
 /// Integrate a single spatial GLV trajectory with explicit termination.
+#[allow(clippy::too_many_arguments)]
 pub fn solve_with_termination(
     gs_i: SystemState<f64>,
     interaction_matrix: &Array2<f64>,
@@ -892,74 +858,94 @@ pub fn solve_with_termination(
     progress_counter: Option<&AtomicUsize>,
     termination: TerminationConfig,
 ) -> Result<SolveOutcome> {
-    solve_impl(
+    solve_with_integrator(
         gs_i,
         interaction_matrix,
         growth_vector,
         diffusion,
+        &mut Rk4::default(),
         dt,
         num_steps,
         save_signal_interval,
         save_space_interval,
         output_path,
         progress_counter,
-        Dynamics::GlvPopulation,
         termination,
     )
 }
 
-/// Integrate a single spatial replicator trajectory and persist split signal/space output.
+/// Integrate a single spatial GLV trajectory with a caller-supplied integrator.
 ///
 /// Details:
-/// - Purpose: Runs one trajectory of arbitrary-dimensional local-simplex
-///   reaction-diffusion over `Mode::Frequency` fields.
+/// - Purpose: Same as `solve_with_termination`, but steps the flattened
+///   reaction-diffusion field with any explicit `Integrator`, including user
+///   implementations. The spatial field has no Jacobian, so implicit schemes
+///   are rejected.
 /// - Parameters:
-///   - `gs_i`: Initial spatial frequency state consumed by the solver.
-///   - `interaction_matrix`: Square interaction matrix `V`.
-///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
-///   - `diffusion`: Per-species diffusion coefficients and grid metadata.
-///   - `dt`: Step size.
-///   - `num_steps`: Number of integration steps.
-///   - `save_signal_interval`: Save aggregate state every Nth step; `t = 0`
-///     is always saved.
-///   - `save_space_interval`: Include full spatial field every Nth step;
-///     `t = 0` is always saved.
-///   - `output_path`: Directory for split signal/space JSON output.
-///   - `progress_counter`: Optional shared progress counter.
-pub fn solve_replicator(
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve`, plus explicit termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_integrator(
     gs_i: SystemState<f64>,
     interaction_matrix: &Array2<f64>,
     growth_vector: Option<&Array1<f64>>,
     diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
     dt: f64,
     num_steps: usize,
     save_signal_interval: usize,
     save_space_interval: usize,
     output_path: &Path,
     progress_counter: Option<&AtomicUsize>,
-) -> Result<SystemState<f64>> {
-    Ok(solve_replicator_with_termination(
-        gs_i,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    if !matches!(gs_i.mode, Mode::Population { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "spatial RK4 currently supports Mode::Population only",
+        ));
+    }
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = GlvPopulation {
         interaction_matrix,
-        growth_vector,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
         diffusion,
+        integrator,
         dt,
         num_steps,
         save_signal_interval,
         save_space_interval,
         output_path,
         progress_counter,
-        TerminationConfig::disabled(),
-    )?
-    .final_state)
+        termination,
+    )
 }
 
-/// Integrate a single spatial replicator trajectory with explicit termination.
-pub fn solve_replicator_with_termination(
+/// Integrate a single spatial GLV trajectory with saturating functional responses.
+///
+/// Details:
+/// - Purpose: Runs `HollingGlv` in every cell, where each cross-species term
+///   `V_ij n_j` saturates in the resource's density (see
+///   `functional_response`), and adds diffusion. Requires `Mode::Population`.
+/// - Parameters:
+///   - `response`: Holling type and per-pair handling times `h` (`d×d`,
+///     nonnegative).
+///   - (others): As in `solve_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_functional_response(
     gs_i: SystemState<f64>,
     interaction_matrix: &Array2<f64>,
     growth_vector: Option<&Array1<f64>>,
+    response: &FunctionalResponse,
     diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
     dt: f64,
     num_steps: usize,
     save_signal_interval: usize,
@@ -968,40 +954,543 @@ pub fn solve_replicator_with_termination(
     progress_counter: Option<&AtomicUsize>,
     termination: TerminationConfig,
 ) -> Result<SolveOutcome> {
-    solve_impl(
-        gs_i,
+    if !matches!(gs_i.mode, Mode::Population { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "spatial RK4 currently supports Mode::Population only",
+        ));
+    }
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+    response.validate(interaction_matrix.nrows())?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = HollingGlv {
         interaction_matrix,
-        growth_vector,
+        growth_vector: &growth_vector,
+        response,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
         diffusion,
+        integrator,
         dt,
         num_steps,
         save_signal_interval,
         save_space_interval,
         output_path,
         progress_counter,
-        Dynamics::LocalReplicatorFrequency,
         termination,
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use ndarray::IxDyn;
-    use std::fs;
-
-    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
-        let path = std::env::temp_dir().join(format!(
-            "glv_spatial_rk4_{test_name}_{}",
-            std::process::id()
-        ));
-        let _ = fs::remove_dir_all(&path);
-        path
-    }
-
-    #[test]
-    fn zero_reaction_and_zero_diffusion_preserves_arbitrary_dimensional_space() {
-        let shape = vec![2, 3, 2, 2];
+/// Integrate a single spatial replicator trajectory and persist split signal/space output.
+///
+/// Details:
+/// - Purpose: Runs one trajectory of arbitrary-dimensional local-simplex
+///   reaction-diffusion over `Mode::Frequency` fields.
+/// - Parameters:
+///   - `gs_i`: Initial spatial frequency state consumed by the solver.
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `diffusion`: Per-species diffusion coefficients and grid metadata.
+///   - `dt`: Step size.
+///   - `num_steps`: Number of integration steps.
+///   - `save_signal_interval`: Save aggregate state every Nth step; `t = 0`
+///     is always saved.
+///   - `save_space_interval`: Include full spatial field every Nth step;
+///     `t = 0` is always saved.
+///   - `output_path`: Directory for split signal/space JSON output.
+///   - `progress_counter`: Optional shared progress counter.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    diffusion: &Diffusion,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+) -> Result<SystemState<f64>> {
+    Ok(solve_replicator_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        diffusion,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single spatial replicator trajectory with explicit termination.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_with_termination(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    diffusion: &Diffusion,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    solve_replicator_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        diffusion,
+        &mut Rk4::default(),
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial replicator trajectory with a caller-supplied integrator.
+///
+/// Details:
+/// - Purpose: Same as `solve_replicator_with_termination`, but steps the flattened
+///   reaction-diffusion field with any explicit `Integrator`, including user
+///   implementations. The spatial field has no Jacobian, so implicit schemes
+///   are rejected.
+/// - Parameters:
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve_replicator`, plus explicit termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_with_integrator(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    if !matches!(gs_i.mode, Mode::Frequency { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "spatial replicator RK4 currently supports Mode::Frequency only",
+        ));
+    }
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = Replicator {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial replicator–mutator trajectory and persist split signal/space output.
+///
+/// Details:
+/// - Purpose: Runs one trajectory of local-simplex reaction-diffusion where
+///   each cell follows `Σ_j Q_ji ν_j f_j - Υ ν_i` over `Mode::Frequency`
+///   fields.
+/// - Parameters:
+///   - `mutation_matrix`: Row-stochastic mutation matrix `Q`.
+///   - (others): As in `solve_replicator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    mutation_matrix: &Array2<f64>,
+    diffusion: &Diffusion,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+) -> Result<SystemState<f64>> {
+    Ok(solve_replicator_mutator_with_termination(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        mutation_matrix,
+        diffusion,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        TerminationConfig::disabled(),
+    )?
+    .final_state)
+}
+
+/// Integrate a single spatial replicator–mutator trajectory with explicit termination.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator_with_termination(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    mutation_matrix: &Array2<f64>,
+    diffusion: &Diffusion,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    solve_replicator_mutator_with_integrator(
+        gs_i,
+        interaction_matrix,
+        growth_vector,
+        mutation_matrix,
+        diffusion,
+        &mut Rk4::default(),
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial replicator–mutator trajectory with a caller-supplied integrator.
+///
+/// Details:
+/// - Purpose: Same as `solve_replicator_mutator_with_termination`, but steps
+///   the flattened reaction-diffusion field with any explicit `Integrator`.
+/// - Parameters:
+///   - `integrator`: Raw step scheme.
+///   - (others): As in `solve_replicator_mutator`, plus explicit termination
+///     behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_replicator_mutator_with_integrator(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    mutation_matrix: &Array2<f64>,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    if !matches!(gs_i.mode, Mode::Frequency { .. }) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "spatial replicator-mutator RK4 currently supports Mode::Frequency only",
+        ));
+    }
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+    validate_mutation_matrix(mutation_matrix, interaction_matrix.nrows())?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = ReplicatorMutator {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        mutation_matrix,
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial open-community trajectory with per-species immigration.
+///
+/// Details:
+/// - Purpose: Adds the immigration source `λ_i` to the per-cell reaction
+///   selected by `gs_i.mode`: GLV `f_i(n) + λ_i` for `Mode::Population`, and
+///   the simplex-centred local replicator `f_i(ν) + λ_i - ν_i Σ_j λ_j` for
+///   `Mode::Frequency`. Every cell receives the same inflow, so species
+///   extinct in a region re-enter locally rather than only by diffusion.
+/// - Parameters:
+///   - `immigration`: Per-species immigration rates `λ` (nonnegative). Each
+///     positive rate must satisfy `λ_i dt >= cutoff`.
+///   - (others): As in `solve_with_integrator` and
+///     `solve_replicator_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_with_immigration(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    immigration: &Array1<f64>,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+    let cutoff = match gs_i.mode {
+        Mode::Frequency { cutoff } | Mode::Population { cutoff, .. } => cutoff,
+    };
+    validate_immigration(immigration, interaction_matrix.nrows(), cutoff, dt)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics: Box<dyn Dynamics> = match gs_i.mode {
+        Mode::Frequency { .. } => Box::new(Immigration::frequency(
+            Replicator {
+                interaction_matrix,
+                growth_vector: &growth_vector,
+            },
+            immigration,
+        )),
+        Mode::Population { .. } => Box::new(Immigration::population(
+            GlvPopulation {
+                interaction_matrix,
+                growth_vector: &growth_vector,
+            },
+            immigration,
+        )),
+    };
+
+    solve_dynamics(
+        gs_i,
+        dynamics.as_ref(),
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial trajectory under time-dependent `g` and `V`.
+///
+/// Details:
+/// - Purpose: Runs the per-cell reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, local replicator for `Mode::Frequency`) with
+///   `forcing` applied at the state's `physical_time`; every cell sees the
+///   same forcing at each stage time.
+/// - Parameters:
+///   - `forcing`: Seasonal or piecewise-constant forcing of `g` and `V`.
+///   - (others): As in `solve_with_integrator` and
+///     `solve_replicator_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_forced(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    forcing: &Forcing,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+    forcing.validate(interaction_matrix.nrows())?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = Forced {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        forcing,
+        frequency: matches!(gs_i.mode, Mode::Frequency { .. }),
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial trajectory with a sparse interaction matrix.
+///
+/// Details:
+/// - Purpose: Runs the per-cell reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, local replicator for `Mode::Frequency`) with `V` in
+///   CSR form, so each cell costs `O(nnz)` instead of `O(d²)`. Results match
+///   `solve_with_integrator` and `solve_replicator_with_integrator` on the
+///   dense copy of `V`.
+/// - Parameters:
+///   - `interaction_matrix`: Square CSR interaction matrix `V`.
+///   - (others): As in `solve_with_integrator` and
+///     `solve_replicator_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_sparse(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &CsrMatrix,
+    growth_vector: Option<&Array1<f64>>,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    validate_interaction_shape(&gs_i, interaction_matrix.dim(), growth_vector)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = SparseInteractions {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        frequency: matches!(gs_i.mode, Mode::Frequency { .. }),
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+/// Integrate a single spatial trajectory with third-order interactions.
+///
+/// Details:
+/// - Purpose: Runs the per-cell reaction selected by `gs_i.mode` (GLV for
+///   `Mode::Population`, local replicator for `Mode::Frequency`) with fitness
+///   `g_i + (Vu)_i + Σ_jk B_ijk u_j u_k` evaluated on each cell's abundances.
+/// - Parameters:
+///   - `tensor`: Dense or sparse third-order interaction tensor `B`, sized to
+///     the species axis.
+///   - (others): As in `solve_with_integrator` and
+///     `solve_replicator_with_integrator`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_higher_order(
+    gs_i: SystemState<f64>,
+    interaction_matrix: &Array2<f64>,
+    growth_vector: Option<&Array1<f64>>,
+    tensor: &InteractionTensor,
+    diffusion: &Diffusion,
+    integrator: &mut dyn Integrator,
+    dt: f64,
+    num_steps: usize,
+    save_signal_interval: usize,
+    save_space_interval: usize,
+    output_path: &Path,
+    progress_counter: Option<&AtomicUsize>,
+    termination: TerminationConfig,
+) -> Result<SolveOutcome> {
+    validate_interactions(&gs_i, interaction_matrix, growth_vector)?;
+    tensor.validate(interaction_matrix.nrows())?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, interaction_matrix.nrows());
+    let dynamics = HigherOrder {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+        tensor,
+        frequency: matches!(gs_i.mode, Mode::Frequency { .. }),
+    };
+
+    solve_dynamics(
+        gs_i,
+        &dynamics,
+        diffusion,
+        integrator,
+        dt,
+        num_steps,
+        save_signal_interval,
+        save_space_interval,
+        output_path,
+        progress_counter,
+        termination,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::events::ScheduledEvent;
+    use crate::solvers::integrator::Scheme;
+    use ndarray::{IxDyn, array};
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "glv_spatial_rk4_{test_name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn zero_reaction_and_zero_diffusion_preserves_arbitrary_dimensional_space() {
+        let shape = vec![2, 3, 2, 2];
         let data = vec![
             1.0, 2.0, 0.5, 1.5, 2.0, 1.0, 3.0, 0.25, 1.25, 2.5, 0.75, 1.75, 1.0, 2.0, 0.5, 1.5,
             2.0, 1.0, 3.0, 0.25, 1.25, 2.5, 0.75, 1.75,
@@ -1083,6 +1572,262 @@ mod tests {
         let _ = fs::remove_dir_all(output_path);
     }
 
+    #[test]
+    fn explicit_integrators_are_pluggable_and_implicit_ones_rejected() {
+        let shape = vec![4, 1];
+        let space =
+            ArrayD::from_shape_vec(IxDyn(&shape), vec![0.2, 0.4, 0.6, 0.8]).expect("valid shape");
+        let make_state = || {
+            SystemState::from_arrays(
+                Mode::Population {
+                    cutoff: None,
+                    carrying_capacity: None,
+                },
+                0,
+                Array1::zeros(1),
+                Some(space.clone()),
+            )
+        };
+        let interaction_matrix = Array2::from_elem((1, 1), -1.0);
+        let growth_vector = Array1::from_elem(1, 1.0);
+        let diffusion = Diffusion::unit_spacing(Array1::from_vec(vec![0.1]), 1, Boundary::Periodic);
+        let output_path = temp_output_dir("integrators");
+
+        let run = |scheme: Scheme| {
+            solve_with_integrator(
+                make_state(),
+                &interaction_matrix,
+                Some(&growth_vector),
+                &diffusion,
+                scheme.integrator().as_mut(),
+                0.01,
+                200,
+                200,
+                200,
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+        };
+
+        let rk4 = run(Scheme::Rk4).expect("rk4 succeeds").final_state;
+        let heun = run(Scheme::Heun).expect("heun succeeds").final_state;
+        let euler = run(Scheme::ForwardEuler)
+            .expect("euler succeeds")
+            .final_state;
+        assert!((rk4.state[0] - heun.state[0]).abs() < 1e-4);
+        assert!((rk4.state[0] - euler.state[0]).abs() < 1e-2);
+
+        let err = run(Scheme::Rosenbrock)
+            .err()
+            .expect("implicit scheme rejected without Jacobian");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn user_dynamics_are_evaluated_per_cell() {
+        let shape = vec![3, 2];
+        let space = ArrayD::from_shape_vec(IxDyn(&shape), vec![0.5, 2.0, 1.0, 1.0, 2.0, 0.5])
+            .expect("valid shape");
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            Array1::zeros(2),
+            Some(space),
+        );
+        // Independent logistic growth to carrying capacity 1 in every cell.
+        let logistic = |u: &[f64], out: &mut [f64]| {
+            for (o, &n) in out.iter_mut().zip(u) {
+                *o = n * (1.0 - n);
+            }
+        };
+        let diffusion = Diffusion::unit_spacing(Array1::zeros(2), 1, Boundary::Neumann);
+        let output_path = temp_output_dir("user_dynamics");
+
+        let out = solve_dynamics(
+            gs,
+            &logistic,
+            &diffusion,
+            &mut Rk4::default(),
+            0.01,
+            3_000,
+            1_000,
+            1_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        let space = out.final_state.space.expect("space retained");
+        assert!(space.iter().all(|x| (x - 1.0).abs() < 1e-9));
+        assert!((out.final_state.state[0] - 3.0).abs() < 1e-8);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn immigration_reseeds_cells_where_a_species_is_absent() {
+        // Species 1 is absent everywhere; species 0 is absent in the middle cell.
+        let shape = vec![3, 2];
+        let space = ArrayD::from_shape_vec(IxDyn(&shape), vec![0.5, 0.0, 0.0, 0.0, 0.5, 0.0])
+            .expect("valid shape");
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: Some(1e-4),
+                carrying_capacity: None,
+            },
+            0,
+            Array1::zeros(2),
+            Some(space),
+        );
+        let interaction_matrix = array![[-1.0, 0.0], [0.0, -1.0]];
+        let growth_vector = array![1.0, -1.0];
+        let immigration = array![0.0, 0.1];
+        let diffusion = Diffusion::unit_spacing(Array1::zeros(2), 1, Boundary::Neumann);
+        let output_path = temp_output_dir("immigration");
+
+        let out = solve_with_immigration(
+            gs,
+            &interaction_matrix,
+            Some(&growth_vector),
+            &immigration,
+            &diffusion,
+            &mut Rk4::default(),
+            0.01,
+            4_000,
+            1_000,
+            1_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("solve succeeds");
+
+        let space = out.final_state.space.expect("space retained");
+        let values = space.as_slice_memory_order().unwrap();
+        let immigrant = (1.4_f64.sqrt() - 1.0) / 2.0;
+        for cell in 0..3 {
+            assert!((values[2 * cell + 1] - immigrant).abs() < 1e-6);
+        }
+        // Without λ_0 or diffusion, the empty middle cell stays empty.
+        assert_eq!(values[2], 0.0);
+        assert!((values[0] - 1.0).abs() < 1e-6);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn sparse_interactions_match_the_dense_solver() {
+        let shape = vec![3, 3];
+        let space = ArrayD::from_shape_vec(
+            IxDyn(&shape),
+            vec![0.5, 0.2, 0.1, 0.1, 0.6, 0.3, 0.3, 0.1, 0.7],
+        )
+        .expect("valid shape");
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: Some(1e-9),
+                carrying_capacity: None,
+            },
+            0,
+            Array1::zeros(3),
+            Some(space),
+        );
+        let interaction_matrix = array![[-1.0, 0.0, -0.4], [0.5, -1.0, 0.0], [0.0, 0.0, -0.8]];
+        let growth_vector = array![1.0, 0.2, 0.5];
+        let diffusion = Diffusion::unit_spacing(array![0.1, 0.05, 0.0], 1, Boundary::Periodic);
+        let output_path = temp_output_dir("sparse");
+
+        let dense = solve_with_integrator(
+            gs.clone(),
+            &interaction_matrix,
+            Some(&growth_vector),
+            &diffusion,
+            &mut Rk4::default(),
+            0.01,
+            500,
+            100,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("dense solve succeeds");
+        let sparse = solve_sparse(
+            gs,
+            &CsrMatrix::from_dense(&interaction_matrix),
+            Some(&growth_vector),
+            &diffusion,
+            &mut Rk4::default(),
+            0.01,
+            500,
+            100,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("sparse solve succeeds");
+
+        assert_eq!(dense.final_state.space, sparse.final_state.space);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn events_introduce_invaders_into_chosen_cells() {
+        let shape = vec![2, 2, 2];
+        let data = vec![1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0];
+        let space = ArrayD::from_shape_vec(IxDyn(&shape), data).expect("valid shape");
+        let gs = SystemState::from_arrays(
+            Mode::Population {
+                cutoff: None,
+                carrying_capacity: None,
+            },
+            0,
+            Array1::zeros(2),
+            Some(space),
+        );
+        let zero = |_: &[f64], out: &mut [f64]| out.fill(0.0);
+        let diffusion = Diffusion::unit_spacing(Array1::zeros(2), 2, Boundary::Periodic);
+        let events = EventSchedule::new()
+            .event(
+                ScheduledEvent::at_step(3)
+                    .introduce(1, 0.5)
+                    .in_cells(&[vec![0, 1], vec![1, 0]]),
+            )
+            .event(ScheduledEvent::at_step(4).introduce(1, 0.25));
+        let output_path = temp_output_dir("events");
+
+        let out = solve_dynamics_with_events(
+            gs,
+            &zero,
+            &diffusion,
+            &mut Rk4::default(),
+            0.01,
+            5,
+            5,
+            5,
+            &output_path,
+            None,
+            TerminationConfig::monoculture_only(1),
+            &events,
+        )
+        .expect("solve succeeds");
+
+        // The monoculture check is held back until the schedule is exhausted.
+        assert_eq!(out.steps_run, 5);
+        assert_eq!(out.events.len(), 2);
+        assert_eq!(out.events[0].cells.as_ref().map(Vec::len), Some(2));
+        let space = out.final_state.space.expect("space retained");
+        let invader: Vec<f64> = space.iter().skip(1).step_by(2).copied().collect();
+        assert_eq!(invader, vec![0.25, 0.75, 0.75, 0.25]);
+        assert!((out.final_state.state[1] - 2.0).abs() < 1e-12);
+        let _ = fs::remove_dir_all(output_path);
+    }
+
     #[test]
     fn replicator_solver_keeps_each_spatial_cell_on_simplex() {
         let shape = vec![2, 2, 2];
@@ -1125,6 +1870,49 @@ mod tests {
         let _ = fs::remove_dir_all(output_path);
     }
 
+    #[test]
+    fn replicator_mutator_cells_reach_the_quasispecies_equilibrium() {
+        let mu = 0.2;
+        let shape = vec![3, 2];
+        let data = vec![0.9, 0.1, 0.5, 0.5, 0.2, 0.8];
+        let space = ArrayD::from_shape_vec(IxDyn(&shape), data).expect("valid shape");
+        let gs = SystemState::from_arrays(
+            Mode::Frequency { cutoff: None },
+            0,
+            Array1::zeros(2),
+            Some(space),
+        );
+        let growth_vector = Array1::from_vec(vec![1.0, 0.0]);
+        let mutation_matrix =
+            Array2::from_shape_vec((2, 2), vec![1.0 - mu, mu, mu, 1.0 - mu]).expect("valid shape");
+        let diffusion =
+            Diffusion::unit_spacing(Array1::from_vec(vec![0.1, 0.1]), 1, Boundary::Periodic);
+        let output_path = temp_output_dir("replicator_mutator");
+
+        let out = solve_replicator_mutator(
+            gs,
+            &Array2::zeros((2, 2)),
+            Some(&growth_vector),
+            &mutation_matrix,
+            &diffusion,
+            0.01,
+            3_000,
+            1_000,
+            1_000,
+            &output_path,
+            None,
+        )
+        .expect("solve succeeds");
+
+        let final_space = out.space.expect("space retained");
+        let values = final_space.as_slice_memory_order().unwrap();
+        for cell in 0..3 {
+            assert!((values[2 * cell] - (1.0 - mu)).abs() < 1e-6);
+            assert!((values[2 * cell] + values[2 * cell + 1] - 1.0).abs() < 1e-12);
+        }
+        let _ = fs::remove_dir_all(output_path);
+    }
+
     #[test]
     fn spatial_save_intervals_split_signal_and_space_outputs() {
         let shape = vec![2, 2, 1];
@@ -14,6 +14,8 @@ use ndarray::{Array1, ArrayD};
 use serde::{Deserialize, Serialize};
 
 use crate::io::WriterStats;
+use crate::solvers::events::EventRecord;
+use crate::solvers::extinctions::ExtinctionRecord;
 use crate::{Mode, SystemState};
 
 /// Which part of a state is compared by steady-state checks.
@@ -93,7 +95,7 @@ impl TerminationConfig {
 }
 
 /// Why a solve call stopped.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum TerminationReason {
     MaxSteps,
     Monoculture {
@@ -119,6 +121,17 @@ impl TerminationReason {
     }
 }
 
+/// Step-control counters reported by adaptive solvers.
+///
+/// `final_time` is the absolute physical time of the final state, matching
+/// `SystemState.physical_time`, not the time elapsed in this run.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct StepStats {
+    pub accepted_steps: usize,
+    pub rejected_steps: usize,
+    pub final_time: f64,
+}
+
 /// Solver result including early-termination metadata.
 #[derive(Clone)]
 pub struct SolveOutcome {
@@ -127,6 +140,9 @@ pub struct SolveOutcome {
     pub reason: TerminationReason,
     pub signal_stats: WriterStats,
     pub space_stats: Option<WriterStats>,
+    pub step_stats: Option<StepStats>,
+    pub events: Vec<EventRecord>,
+    pub extinctions: Vec<ExtinctionRecord>,
 }
 
 /// Stateful bounded-history termination checker.
@@ -159,7 +175,7 @@ impl TerminationChecker {
 
     #[inline]
     pub fn should_check(&self, step: usize) -> bool {
-        step % self.config.check_interval == 0
+        step.is_multiple_of(self.config.check_interval)
     }
 
     pub fn check(&mut self, gs: &SystemState<f64>, step: usize) -> Option<TerminationReason> {
@@ -167,10 +183,10 @@ impl TerminationChecker {
             return None;
         }
 
-        if self.config.monoculture {
-            if let Some(reason) = monoculture_reason(gs, self.config.survivor_tolerance, step) {
-                return Some(reason);
-            }
+        if self.config.monoculture
+            && let Some(reason) = monoculture_reason(gs, self.config.survivor_tolerance, step)
+        {
+            return Some(reason);
         }
 
         match self.config.steady_state {
@@ -197,16 +213,15 @@ impl TerminationChecker {
                     }
                 }
 
-                if let Some(oscillation) = oscillation {
-                    if step >= oscillation.min_steps {
-                        if let Some(period) = oscillation_period(&self.history, oscillation) {
-                            return Some(TerminationReason::OscillatorySteadyState {
-                                observable: self.config.observable,
-                                period,
-                                step,
-                            });
-                        }
-                    }
+                if let Some(oscillation) = oscillation
+                    && step >= oscillation.min_steps
+                    && let Some(period) = oscillation_period(&self.history, oscillation)
+                {
+                    return Some(TerminationReason::OscillatorySteadyState {
+                        observable: self.config.observable,
+                        period,
+                        step,
+                    });
                 }
 
                 None
@@ -3,7 +3,8 @@ Single spatiotemporal state.
 
 Purpose:
     `SystemState` stores one ecological snapshot: representation mode, integer
-    time, global taxon vector, optional spatial field, and cached mass.
+    step counter, physical time, global taxon vector, optional spatial field,
+    and cached mass.
 
 Representation modes:
     - `Frequency`: entries live on the simplex and `mass` is normalized to one.
@@ -38,10 +39,17 @@ pub enum Mode<T> {
 }
 
 /// Snapshot at one integer time index.
+///
+/// Details:
+/// - Purpose: `time` counts solver steps (events for SSA); `physical_time`
+///   is the simulated time `t` reached by those steps. Constructors start
+///   at `physical_time = 0`, and solvers advance it from its initial value.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SystemState<T> {
     pub mode: Mode<T>,
     pub time: usize,
+    #[serde(default)]
+    pub physical_time: f64,
     pub state: Array1<T>,
     pub space: Option<ArrayD<T>>,
     pub mass: T,
@@ -93,6 +101,7 @@ where
         Self {
             mode,
             time,
+            physical_time: 0.0,
             state,
             space,
             mass,
@@ -116,10 +125,7 @@ where
         space_shape: Option<&[usize]>,
     ) -> Self {
         let state = Array1::from_elem(num_taxa, T::default());
-        let space = match space_shape {
-            Some(shape) => Some(ArrayD::from_elem(IxDyn(shape), T::default())),
-            None => None,
-        };
+        let space = space_shape.map(|shape| ArrayD::from_elem(IxDyn(shape), T::default()));
 
         Self::from_arrays(mode, time, state, space)
     }
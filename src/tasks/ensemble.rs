@@ -0,0 +1,429 @@
+/*!
+Replicate ensemble task.
+
+Purpose:
+    This task fans independent replicates of one task out over rayon. Replicate
+    `k` runs on stream `k` of the ensemble seed and writes its own output under
+    `output/<label>/replicate_<k>/`. The ensemble root gets an `ensemble.json`
+    that aggregates termination reasons, steps run, and fixation outcomes
+    across replicates.
+
+Fixation contract:
+    Fixation is read from each replicate's termination reason. A
+    `Monoculture` stop with a surviving index counts as fixation of that
+    species, a stop without one counts as extinction, and any other reason
+    counts as unfixed. Enable monoculture termination to classify replicates.
+*/
+
+use std::fs::{read_dir, remove_dir_all, remove_file};
+use std::io::{Error, ErrorKind, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{Array1, Array2};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::solvers::non_spatial::sde::{Calculus, SdeScheme};
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::{TerminationConfig, TerminationReason};
+use crate::tasks::metadata::{TaskOutcome, load_json, output_label, save_json};
+use crate::tasks::replicator_demographic;
+
+/// One replicate's entry in `ensemble.json`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReplicateSummary {
+    pub replicate: usize,
+    pub seed: RngSeed,
+    pub steps_run: usize,
+    pub termination_reason: TerminationReason,
+}
+
+/// Replicate counts per termination reason.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TerminationCounts {
+    pub max_steps: usize,
+    pub monoculture: usize,
+    pub fixed_point: usize,
+    pub oscillatory_steady_state: usize,
+}
+
+/// Minimum, mean, and maximum steps run over replicates.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct StepsRunSummary {
+    pub min: usize,
+    pub mean: f64,
+    pub max: usize,
+}
+
+/// Replicate counts per fixation outcome.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FixationCounts {
+    /// `by_species[i]`: replicates in which species `i` fixed.
+    pub by_species: Vec<usize>,
+    pub extinct: usize,
+    pub unfixed: usize,
+}
+
+/// Ensemble summary persisted as `ensemble.json` at the ensemble root.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EnsembleOutcome {
+    pub task: String,
+    pub output_label: String,
+    pub seed: RngSeed,
+    pub num_replicates: usize,
+    pub num_species: usize,
+    pub termination_counts: TerminationCounts,
+    pub steps_run: StepsRunSummary,
+    pub fixation: FixationCounts,
+    pub replicates: Vec<ReplicateSummary>,
+}
+
+impl EnsembleOutcome {
+    /// Aggregate per-replicate task outcomes, indexed by replicate.
+    pub fn from_replicates(
+        task: &str,
+        output_label: &str,
+        seed: RngSeed,
+        outcomes: &[TaskOutcome],
+    ) -> Self {
+        let num_species = outcomes.first().map_or(0, |o| o.num_species);
+        let mut termination_counts = TerminationCounts::default();
+        let mut fixation = FixationCounts {
+            by_species: vec![0; num_species],
+            ..FixationCounts::default()
+        };
+
+        for outcome in outcomes {
+            match &outcome.termination_reason {
+                TerminationReason::MaxSteps => {
+                    termination_counts.max_steps += 1;
+                    fixation.unfixed += 1;
+                }
+                TerminationReason::Monoculture {
+                    surviving_index, ..
+                } => {
+                    termination_counts.monoculture += 1;
+                    match surviving_index {
+                        Some(i) if *i < num_species => fixation.by_species[*i] += 1,
+                        Some(_) => fixation.unfixed += 1,
+                        None => fixation.extinct += 1,
+                    }
+                }
+                TerminationReason::FixedPoint { .. } => {
+                    termination_counts.fixed_point += 1;
+                    fixation.unfixed += 1;
+                }
+                TerminationReason::OscillatorySteadyState { .. } => {
+                    termination_counts.oscillatory_steady_state += 1;
+                    fixation.unfixed += 1;
+                }
+            }
+        }
+
+        let steps_run = if outcomes.is_empty() {
+            StepsRunSummary::default()
+        } else {
+            let total: usize = outcomes.iter().map(|o| o.steps_run).sum();
+            StepsRunSummary {
+                min: outcomes.iter().map(|o| o.steps_run).min().unwrap_or(0),
+                mean: total as f64 / outcomes.len() as f64,
+                max: outcomes.iter().map(|o| o.steps_run).max().unwrap_or(0),
+            }
+        };
+
+        let replicates = outcomes
+            .iter()
+            .enumerate()
+            .map(|(k, o)| ReplicateSummary {
+                replicate: k,
+                seed: seed.replicate(k as u64),
+                steps_run: o.steps_run,
+                termination_reason: o.termination_reason,
+            })
+            .collect();
+
+        Self {
+            task: task.to_owned(),
+            output_label: output_label.to_owned(),
+            seed,
+            num_replicates: outcomes.len(),
+            num_species,
+            termination_counts,
+            steps_run,
+            fixation,
+            replicates,
+        }
+    }
+}
+
+/// Output directory of replicate `k` under the ensemble root.
+pub fn replicate_dir(output_path: &Path, k: usize) -> PathBuf {
+    output_path.join(format!("replicate_{k}"))
+}
+
+/// Run `num_replicates` replicates of one task in parallel.
+///
+/// Details:
+/// - Purpose: Calls `replicate(seed.replicate(k), replicate_dir)` for every
+///   `k` on the rayon pool, then writes `ensemble.json`. Stale
+///   `replicate_*` directories and `ensemble.json` are removed first.
+/// - Parameters:
+///   - `task`: Task name recorded in `ensemble.json`.
+///   - `num_replicates`: Number of replicates.
+///   - `seed`: Ensemble key; replicate `k` runs on stream `k`.
+///   - `output_path`: Ensemble root directory.
+///   - `progress_counter`: Optional shared counter of finished replicates.
+///   - `replicate`: Runs one replicate into the given directory.
+pub fn run<F>(
+    task: &str,                             // task name
+    num_replicates: usize,                  // number of replicates
+    seed: RngSeed,                          // ensemble key
+    output_path: &Path,                     // ensemble root dir
+    progress_counter: Option<&AtomicUsize>, // finished replicates
+    replicate: F,                           // one replicate run
+) -> Result<EnsembleOutcome>
+where
+    F: Fn(RngSeed, &Path) -> Result<TaskOutcome> + Sync,
+{
+    prepare_ensemble_dir(output_path)?;
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let outcomes = (0..num_replicates)
+        .into_par_iter()
+        .map(|k| {
+            let outcome = replicate(seed.replicate(k as u64), &replicate_dir(output_path, k))?;
+            if let Some(counter) = progress_counter {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(outcome)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let ensemble =
+        EnsembleOutcome::from_replicates(task, &output_label(output_path), seed, &outcomes);
+    save_ensemble_metadata(output_path, &ensemble)?;
+
+    Ok(ensemble)
+}
+
+/// Run an ensemble of demographic-noise replicator replicates.
+///
+/// Details:
+/// - Purpose: Runs `replicator_demographic::run` once per replicate with
+///   identical parameters and independent noise streams.
+/// - Parameters:
+///   - `num_replicates`: Number of replicates.
+///   - `seed`: Ensemble key; replicate `k` runs on stream `k`.
+///   - `progress_counter`: Optional shared counter of finished replicates.
+///   - (others): As in `replicator_demographic::run`.
+#[allow(clippy::too_many_arguments)]
+pub fn run_replicator_demographic(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    cutoff: f64,                            // cutoff
+    sigma: f64,                             // demographic noise strength
+    sde_scheme: SdeScheme,                  // noise scheme
+    calculus: Calculus,                     // noise calculus
+    dt: f64,                                // step size
+    total_steps: usize,                     // total solver steps
+    save_interval: usize,                   // save every N steps
+    num_replicates: usize,                  // number of replicates
+    seed: RngSeed,                          // ensemble key
+    output_path: &Path,                     // ensemble root dir
+    progress_counter: Option<&AtomicUsize>, // finished replicates
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<EnsembleOutcome> {
+    run(
+        "replicator_demographic",
+        num_replicates,
+        seed,
+        output_path,
+        progress_counter,
+        |replicate_seed, replicate_path| {
+            replicator_demographic::run(
+                interaction_matrix,
+                growth_vector,
+                cutoff,
+                sigma,
+                sde_scheme,
+                calculus,
+                replicate_seed,
+                dt,
+                total_steps,
+                save_interval,
+                replicate_path,
+                None,
+                termination,
+            )
+        },
+    )
+}
+
+fn prepare_ensemble_dir(output_path: &Path) -> Result<()> {
+    if output_path.is_file() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("output path {} is a file", output_path.display()),
+        ));
+    }
+    if !output_path.exists() {
+        return Ok(());
+    }
+
+    let entries = read_dir(output_path).map_err(|e| {
+        Error::new(
+            e.kind(),
+            format!("prepare_ensemble_dir: read {}: {e}", output_path.display()),
+        )
+    })?;
+    for entry in entries {
+        let path = entry?.path();
+        let is_replicate = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("replicate_"));
+        if is_replicate && path.is_dir() {
+            remove_dir_all(&path).map_err(|e| {
+                Error::new(
+                    e.kind(),
+                    format!("prepare_ensemble_dir: remove {}: {e}", path.display()),
+                )
+            })?;
+        }
+    }
+
+    let metadata = output_path.join("ensemble.json");
+    if metadata.exists() {
+        remove_file(&metadata).map_err(|e| {
+            Error::new(
+                e.kind(),
+                format!("prepare_ensemble_dir: remove {}: {e}", metadata.display()),
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn save_ensemble_metadata(output_path: &Path, outcome: &EnsembleOutcome) -> Result<()> {
+    save_json(
+        &output_path.join("ensemble.json"),
+        outcome,
+        "save_ensemble_metadata",
+    )
+}
+
+pub fn load_ensemble_metadata(path: &Path) -> Result<EnsembleOutcome> {
+    load_json(path, "load_ensemble_metadata")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::WriterStats;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("glv_ensemble_{test_name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    fn outcome(steps_run: usize, termination_reason: TerminationReason) -> TaskOutcome {
+        TaskOutcome::non_spatial(
+            "replicator_demographic",
+            "well_mixed_replicator",
+            "replicate",
+            100,
+            0.01,
+            10,
+            steps_run,
+            termination_reason,
+            WriterStats::default(),
+            3,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn aggregates_termination_reasons_steps_and_fixation() {
+        let monoculture = |surviving_index, step| TerminationReason::Monoculture {
+            surviving_index,
+            step,
+        };
+        let outcomes = [
+            outcome(100, TerminationReason::MaxSteps),
+            outcome(40, monoculture(Some(2), 40)),
+            outcome(60, monoculture(Some(2), 60)),
+            outcome(20, monoculture(None, 20)),
+        ];
+
+        let ensemble = EnsembleOutcome::from_replicates("t", "label", RngSeed::new(3), &outcomes);
+        assert_eq!(ensemble.num_replicates, 4);
+        assert_eq!(ensemble.termination_counts.max_steps, 1);
+        assert_eq!(ensemble.termination_counts.monoculture, 3);
+        assert_eq!(ensemble.fixation.by_species, vec![0, 0, 2]);
+        assert_eq!(ensemble.fixation.extinct, 1);
+        assert_eq!(ensemble.fixation.unfixed, 1);
+        assert_eq!(ensemble.steps_run.min, 20);
+        assert_eq!(ensemble.steps_run.max, 100);
+        assert_eq!(ensemble.steps_run.mean, 55.0);
+        assert_eq!(ensemble.replicates[3].seed, RngSeed::new(3).replicate(3));
+    }
+
+    #[test]
+    fn replicates_write_separate_dirs_and_replay_from_the_seed() {
+        let output_path = temp_output_dir("demographic");
+        fs::create_dir_all(replicate_dir(&output_path, 7)).expect("stale replicate dir");
+        let interaction_matrix = array![[0.0, 1.0], [-1.0, 0.0]];
+        let run_ensemble = || {
+            run_replicator_demographic(
+                &interaction_matrix,
+                None,
+                1e-6,
+                0.05,
+                SdeScheme::EulerMaruyama,
+                Calculus::Ito,
+                0.01,
+                50,
+                10,
+                3,
+                RngSeed::new(11),
+                &output_path,
+                None,
+                TerminationConfig::disabled(),
+            )
+            .expect("ensemble succeeds")
+        };
+
+        let ensemble = run_ensemble();
+        let signal = |k| fs::read(replicate_dir(&output_path, k).join("signal/1.json")).unwrap();
+        let first = (0..3).map(signal).collect::<Vec<_>>();
+        assert_ne!(first[0], first[1]);
+        assert!(!replicate_dir(&output_path, 7).exists());
+        assert!(
+            replicate_dir(&output_path, 2)
+                .join("metadata.json")
+                .is_file()
+        );
+
+        let loaded =
+            load_ensemble_metadata(&output_path.join("ensemble.json")).expect("ensemble loads");
+        assert_eq!(loaded.num_replicates, 3);
+        assert_eq!(loaded.termination_counts.max_steps, 3);
+        assert_eq!(loaded.fixation.unfixed, 3);
+        assert_eq!(ensemble.steps_run.mean, 50.0);
+
+        run_ensemble();
+        assert_eq!((0..3).map(signal).collect::<Vec<_>>(), first);
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
@@ -1,21 +1,105 @@
 /*!
-Deterministic GLV task placeholder.
+Deterministic GLV task.
 
 Purpose:
-    This module reserves the deterministic GLV task entry point while the
-    implemented solver stack remains replicator-form.
+    This task wires a well-mixed population initial condition into the RK4
+    GLV solver and writes automatically sized JSON time-series chunks.
 */
 
-use std::io::{Error, ErrorKind, Result};
+use std::io::Result;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
 
-/// Placeholder for deterministic GLV task wiring.
+use ndarray::{Array1, Array2};
+
+use crate::Mode;
+use crate::solvers::non_spatial::noise::Noise;
+use crate::solvers::non_spatial::rk4::solve_glv_with_termination;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::TerminationConfig;
+use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
+use crate::utils::create_well_mixed_gs;
+
+/// Run one trajectory and let the signal writer chunk output files by size.
 ///
-/// The current solver stack is replicator-form only. Callers should use
-/// `tasks::replicator_deterministic::run` until a dedicated GLV RHS/integrator
-/// is introduced.
-pub fn run() -> Result<()> {
-    Err(Error::new(
-        ErrorKind::Unsupported,
-        "lv_deterministic is not implemented; use replicator_deterministic::run",
-    ))
+/// Details:
+/// - Purpose: Runs deterministic well-mixed GLV dynamics for `total_steps`.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `cutoff`: Population cutoff used by state sanitization.
+///   - `carrying_capacity`: Optional global population cap.
+///   - `initial_population`: Initial population per species.
+///   - `dt`: Step size.
+///   - `total_steps`: Total solver steps to execute.
+///   - `save_interval`: Save every Nth step.
+///   - `output_path`: Root output directory.
+///   - `progress_counter`: Optional shared progress counter.
+///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    cutoff: f64,                            // cutoff
+    carrying_capacity: Option<f64>,         // optional global cap
+    initial_population: f64,                // initial population per species
+    dt: f64,                                // step size
+    total_steps: usize,                     // total solver steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // root output dir
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<TaskOutcome> {
+    let d = interaction_matrix.nrows();
+    debug_assert_eq!(
+        interaction_matrix.ncols(),
+        d,
+        "interaction_matrix must be square"
+    );
+    if let Some(g) = growth_vector {
+        debug_assert_eq!(g.len(), d, "growth_vector length must match V");
+    }
+
+    // Initial condition: equal populations (n_i = initial_population).
+    let mode = Mode::Population {
+        cutoff: Some(cutoff),
+        carrying_capacity,
+    };
+    let gs = create_well_mixed_gs(mode, d, Some(initial_population));
+    prepare_output_dir(output_path)?;
+
+    let start_physical_time = gs.physical_time;
+    let outcome = solve_glv_with_termination(
+        gs,                 // initial state
+        interaction_matrix, // V
+        growth_vector,      // g
+        Noise::none(),      // deterministic run
+        RngSeed::new(0),    // unused without noise
+        dt,                 // step size
+        total_steps,        // steps
+        save_interval,      // save every N steps
+        output_path,        // output target
+        progress_counter,
+        termination,
+    )?;
+
+    let mut task_outcome = TaskOutcome::non_spatial(
+        "lv_deterministic",
+        "well_mixed_glv",
+        &output_label(output_path),
+        total_steps,
+        dt,
+        save_interval,
+        outcome.steps_run,
+        outcome.reason,
+        outcome.signal_stats,
+        d,
+        Some(cutoff),
+        carrying_capacity,
+        termination.survivor_tolerance,
+    );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
+    save_metadata(output_path, &task_outcome)?;
+
+    Ok(task_outcome)
 }
@@ -36,6 +36,7 @@ use crate::utils::create_uniform_spatial_population_gs;
 ///   - `output_path`: Root output directory.
 ///   - `progress_counter`: Optional shared progress counter.
 ///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     interaction_matrix: &Array2<f64>,       // V
     growth_vector: Option<&Array1<f64>>,    // g override
@@ -80,6 +81,7 @@ pub fn run(
     );
     prepare_output_dir(output_path)?;
 
+    let start_physical_time = gs.physical_time;
     let outcome = solve_with_termination(
         gs,                 // initial state
         interaction_matrix, // V
@@ -94,7 +96,7 @@ pub fn run(
         termination,
     )?;
 
-    let task_outcome = TaskOutcome::spatial(
+    let mut task_outcome = TaskOutcome::spatial(
         "lv_diffusive_deterministic",
         "spatial_glv",
         &output_label(output_path),
@@ -111,6 +113,7 @@ pub fn run(
         carrying_capacity,
         termination.survivor_tolerance,
     );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
     save_metadata(output_path, &task_outcome)?;
 
     Ok(task_outcome)
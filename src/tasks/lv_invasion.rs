@@ -0,0 +1,132 @@
+/*!
+GLV invasion task.
+
+Purpose:
+    This task starts a well-mixed GLV community from a subset of the species
+    pool and introduces the remaining species through an `EventSchedule`,
+    either at fixed steps or once the resident community reaches a steady
+    state. Applied events are marked in the signal stream and listed in
+    `metadata.json`.
+*/
+
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+
+use ndarray::{Array1, Array2};
+
+use crate::solvers::dynamics::{GlvPopulation, growth_vector_or_zeros};
+use crate::solvers::events::EventSchedule;
+use crate::solvers::integrator::Rk4;
+use crate::solvers::non_spatial::noise::Noise;
+use crate::solvers::non_spatial::rk4::solve_dynamics_with_events;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::TerminationConfig;
+use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
+use crate::{Mode, SystemState};
+
+/// Run one invasion trajectory and let the signal writer chunk output files by size.
+///
+/// Details:
+/// - Purpose: Runs deterministic well-mixed GLV dynamics for `total_steps`,
+///   starting with only `residents` present and applying `events` in order.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `cutoff`: Population cutoff used by state sanitization.
+///   - `carrying_capacity`: Optional global population cap.
+///   - `residents`: Species present at `t = 0`.
+///   - `initial_population`: Initial population per resident species.
+///   - `events`: Ordered introductions of invaders.
+///   - `dt`: Step size.
+///   - `total_steps`: Total solver steps to execute.
+///   - `save_interval`: Save every Nth step.
+///   - `output_path`: Root output directory.
+///   - `progress_counter`: Optional shared progress counter.
+///   - `termination`: Early-termination behavior once every event has fired.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    cutoff: f64,                            // cutoff
+    carrying_capacity: Option<f64>,         // optional global cap
+    residents: &[usize],                    // species present at t=0
+    initial_population: f64,                // initial population per resident
+    events: &EventSchedule,                 // scheduled introductions
+    dt: f64,                                // step size
+    total_steps: usize,                     // total solver steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // root output dir
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<TaskOutcome> {
+    let d = interaction_matrix.nrows();
+    debug_assert_eq!(
+        interaction_matrix.ncols(),
+        d,
+        "interaction_matrix must be square"
+    );
+    if let Some(g) = growth_vector {
+        debug_assert_eq!(g.len(), d, "growth_vector length must match V");
+    }
+    if residents.iter().any(|&i| i >= d) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "resident index is outside the species pool",
+        ));
+    }
+
+    // Initial condition: residents at initial_population, invaders absent.
+    let mode = Mode::Population {
+        cutoff: Some(cutoff),
+        carrying_capacity,
+    };
+    let mut state = Array1::zeros(d);
+    for &i in residents {
+        state[i] = initial_population;
+    }
+    let gs = SystemState::from_arrays(mode, 0, state, None);
+    prepare_output_dir(output_path)?;
+
+    let growth_vector = growth_vector_or_zeros(growth_vector, d);
+    let dynamics = GlvPopulation {
+        interaction_matrix,
+        growth_vector: &growth_vector,
+    };
+    let start_physical_time = gs.physical_time;
+    let outcome = solve_dynamics_with_events(
+        gs,                  // initial state
+        &dynamics,           // GLV reaction term
+        Noise::none(),       // deterministic run
+        RngSeed::new(0),     // unused without noise
+        &mut Rk4::default(), // raw step scheme
+        dt,                  // step size
+        total_steps,         // steps
+        save_interval,       // save every N steps
+        output_path,         // output target
+        progress_counter,
+        termination,
+        events,
+    )?;
+
+    let mut task_outcome = TaskOutcome::non_spatial(
+        "lv_invasion",
+        "well_mixed_glv",
+        &output_label(output_path),
+        total_steps,
+        dt,
+        save_interval,
+        outcome.steps_run,
+        outcome.reason,
+        outcome.signal_stats,
+        d,
+        Some(cutoff),
+        carrying_capacity,
+        termination.survivor_tolerance,
+    );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
+    task_outcome.events = Some(outcome.events);
+    save_metadata(output_path, &task_outcome)?;
+
+    Ok(task_outcome)
+}
@@ -0,0 +1,104 @@
+/*!
+Tau-leaping GLV task.
+
+Purpose:
+    This task wires a well-mixed integer population initial condition into the
+    adaptive tau-leaping solver and writes automatically sized JSON
+    time-series chunks on a regular physical-time grid. The leap
+    configuration and seed are recorded in `metadata.json`.
+*/
+
+use std::io::Result;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+
+use ndarray::{Array1, Array2};
+
+use crate::Mode;
+use crate::solvers::non_spatial::tau_leaping::{
+    TauLeapConfig, solve_glv_tau_leap_with_termination,
+};
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::TerminationConfig;
+use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
+use crate::utils::create_well_mixed_gs;
+
+/// Run one tau-leaping trajectory and let the signal writer chunk output files by size.
+///
+/// Details:
+/// - Purpose: Runs large integer-count well-mixed GLV dynamics up to
+///   `config.t_end` with adaptive tau-leaping and an exact SSA fallback.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `cutoff`: Population cutoff applied after every leap or event.
+///   - `carrying_capacity`: Optional global population cap; leaps never exceed it.
+///   - `initial_population`: Initial count per species (rounded).
+///   - `config`: Leap control, SSA fallback, and physical-time save grid.
+///   - `seed`: Random stream; recorded in `metadata.json` for replay.
+///   - `output_path`: Root output directory.
+///   - `progress_counter`: Optional shared progress counter (steps).
+///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    cutoff: f64,                            // cutoff
+    carrying_capacity: Option<f64>,         // optional global cap
+    initial_population: f64,                // initial count per species
+    config: TauLeapConfig,                  // leap control and time grid
+    seed: RngSeed,                          // random stream key and index
+    output_path: &Path,                     // root output dir
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<TaskOutcome> {
+    let d = interaction_matrix.nrows();
+    debug_assert_eq!(
+        interaction_matrix.ncols(),
+        d,
+        "interaction_matrix must be square"
+    );
+    if let Some(g) = growth_vector {
+        debug_assert_eq!(g.len(), d, "growth_vector length must match V");
+    }
+
+    // Initial condition: equal counts (n_i = initial_population).
+    let mode = Mode::Population {
+        cutoff: Some(cutoff),
+        carrying_capacity,
+    };
+    let gs = create_well_mixed_gs(mode, d, Some(initial_population));
+    prepare_output_dir(output_path)?;
+
+    let start_physical_time = gs.physical_time;
+    let outcome = solve_glv_tau_leap_with_termination(
+        gs,                 // initial state
+        interaction_matrix, // V
+        growth_vector,      // g
+        config,             // leap control and time grid
+        seed,               // random stream
+        output_path,        // output target
+        progress_counter,
+        termination,
+    )?;
+
+    let mut task_outcome = TaskOutcome::non_spatial_tau_leap(
+        "lv_tau_leaping",
+        "well_mixed_glv",
+        &output_label(output_path),
+        config,
+        outcome.steps_run,
+        outcome.reason,
+        outcome.signal_stats,
+        outcome.step_stats.unwrap_or_default(),
+        d,
+        Some(cutoff),
+        carrying_capacity,
+        termination.survivor_tolerance,
+    );
+    task_outcome.seed = Some(seed);
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
+    save_metadata(output_path, &task_outcome)?;
+
+    Ok(task_outcome)
+}
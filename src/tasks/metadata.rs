@@ -4,17 +4,30 @@ Task metadata output.
 Purpose:
     Task runners persist one `metadata.json` file beside signal/space output so
     downstream tools can inspect what was requested, what ran, why it stopped,
-    and how many chunks were written.
+    and how many chunks were written. The per-species extinction log goes to
+    `extinctions.json` in the same directory, with its counts summarized in
+    `TaskOutcome.extinctions`.
 */
 
 use std::fs::{File, create_dir_all, read_to_string, remove_dir_all, remove_file};
 use std::io::{BufWriter, Error, ErrorKind, Result};
 use std::path::Path;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::io::WriterStats;
-use crate::solvers::termination::TerminationReason;
+use crate::solvers::events::EventRecord;
+use crate::solvers::extinctions::{ExtinctionRecord, ExtinctionSummary};
+use crate::solvers::forcing::Forcing;
+use crate::solvers::functional_response::FunctionalResponse;
+use crate::solvers::non_spatial::finite_population::FinitePopulation;
+use crate::solvers::non_spatial::gillespie::SsaConfig;
+use crate::solvers::non_spatial::noise::Noise;
+use crate::solvers::non_spatial::rk45::AdaptiveConfig;
+use crate::solvers::non_spatial::tau_leaping::TauLeapConfig;
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::{SolveOutcome, StepStats, TerminationReason};
 use crate::{SIGNAL_OUTPUT_FILE_SIZE, SPACE_OUTPUT_FILE_SIZE};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -26,6 +39,10 @@ pub struct TaskOutcome {
     pub steps_run: usize,
     pub start_time: usize,
     pub end_time: usize,
+    #[serde(default)]
+    pub start_physical_time: Option<f64>,
+    #[serde(default)]
+    pub end_physical_time: Option<f64>,
     pub dt: f64,
     pub signal_save_interval: usize,
     pub space_save_interval: Option<usize>,
@@ -39,9 +56,21 @@ pub struct TaskOutcome {
     pub space: Option<WriterStats>,
     pub signal_chunk_bytes: usize,
     pub space_chunk_bytes: Option<usize>,
+    pub adaptive: Option<AdaptiveConfig>,
+    pub step_stats: Option<StepStats>,
+    pub noise: Option<Noise>,
+    pub ssa: Option<SsaConfig>,
+    pub tau_leap: Option<TauLeapConfig>,
+    pub finite_population: Option<FinitePopulation>,
+    pub seed: Option<RngSeed>,
+    pub events: Option<Vec<EventRecord>>,
+    pub forcing: Option<Forcing>,
+    pub functional_response: Option<FunctionalResponse>,
+    pub extinctions: Option<ExtinctionSummary>,
 }
 
 impl TaskOutcome {
+    #[allow(clippy::too_many_arguments)]
     pub fn non_spatial(
         task: &str,
         model: &str,
@@ -54,6 +83,7 @@ impl TaskOutcome {
         signal: WriterStats,
         num_species: usize,
         cutoff: Option<f64>,
+        carrying_capacity: Option<f64>,
         survivor_tolerance: Option<f64>,
     ) -> Self {
         Self {
@@ -64,22 +94,36 @@ impl TaskOutcome {
             steps_run,
             start_time: 0,
             end_time: steps_run,
+            start_physical_time: None,
+            end_physical_time: None,
             dt,
             signal_save_interval: save_interval,
             space_save_interval: None,
             num_species,
             spatial_shape: None,
             cutoff,
-            carrying_capacity: None,
+            carrying_capacity,
             survivor_tolerance,
             termination_reason,
             signal,
             space: None,
             signal_chunk_bytes: SIGNAL_OUTPUT_FILE_SIZE,
             space_chunk_bytes: None,
+            adaptive: None,
+            step_stats: None,
+            noise: None,
+            ssa: None,
+            tau_leap: None,
+            finite_population: None,
+            seed: None,
+            events: None,
+            forcing: None,
+            functional_response: None,
+            extinctions: None,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn spatial(
         task: &str,
         model: &str,
@@ -105,6 +149,8 @@ impl TaskOutcome {
             steps_run,
             start_time: 0,
             end_time: steps_run,
+            start_physical_time: None,
+            end_physical_time: None,
             dt,
             signal_save_interval: save_interval,
             space_save_interval: Some(save_interval),
@@ -118,8 +164,157 @@ impl TaskOutcome {
             space: Some(space),
             signal_chunk_bytes: SIGNAL_OUTPUT_FILE_SIZE,
             space_chunk_bytes: Some(SPACE_OUTPUT_FILE_SIZE),
+            adaptive: None,
+            step_stats: None,
+            noise: None,
+            ssa: None,
+            tau_leap: None,
+            finite_population: None,
+            seed: None,
+            events: None,
+            forcing: None,
+            functional_response: None,
+            extinctions: None,
         }
     }
+
+    /// Summarize an adaptive well-mixed run.
+    ///
+    /// Signal samples are saved on the physical-time grid in
+    /// `adaptive.save_time_interval`, so `signal_save_interval` is zero and
+    /// `dt` records the initial trial step.
+    #[allow(clippy::too_many_arguments)]
+    pub fn non_spatial_adaptive(
+        task: &str,
+        model: &str,
+        output_label: &str,
+        config: AdaptiveConfig,
+        steps_run: usize,
+        termination_reason: TerminationReason,
+        signal: WriterStats,
+        step_stats: StepStats,
+        num_species: usize,
+        cutoff: Option<f64>,
+        carrying_capacity: Option<f64>,
+        survivor_tolerance: Option<f64>,
+    ) -> Self {
+        let mut outcome = Self::non_spatial(
+            task,
+            model,
+            output_label,
+            config.max_steps,
+            config.dt_initial,
+            0,
+            steps_run,
+            termination_reason,
+            signal,
+            num_species,
+            cutoff,
+            carrying_capacity,
+            survivor_tolerance,
+        );
+        outcome.adaptive = Some(config);
+        outcome.step_stats = Some(step_stats);
+        outcome
+    }
+
+    /// Summarize an exact stochastic simulation run.
+    ///
+    /// Signal samples are saved on the physical-time grid in
+    /// `ssa.save_time_interval`, so `signal_save_interval` is zero, `dt` is
+    /// zero, and `steps_run` counts fired reactions.
+    #[allow(clippy::too_many_arguments)]
+    pub fn non_spatial_ssa(
+        task: &str,
+        model: &str,
+        output_label: &str,
+        config: SsaConfig,
+        steps_run: usize,
+        termination_reason: TerminationReason,
+        signal: WriterStats,
+        step_stats: StepStats,
+        num_species: usize,
+        cutoff: Option<f64>,
+        carrying_capacity: Option<f64>,
+        survivor_tolerance: Option<f64>,
+    ) -> Self {
+        let mut outcome = Self::non_spatial(
+            task,
+            model,
+            output_label,
+            config.max_events,
+            0.0,
+            0,
+            steps_run,
+            termination_reason,
+            signal,
+            num_species,
+            cutoff,
+            carrying_capacity,
+            survivor_tolerance,
+        );
+        outcome.ssa = Some(config);
+        outcome.step_stats = Some(step_stats);
+        outcome
+    }
+
+    /// Summarize a tau-leaping run.
+    ///
+    /// Signal samples are saved on the physical-time grid in
+    /// `tau_leap.save_time_interval`, so `signal_save_interval` and `dt` are
+    /// zero and `steps_run` counts accepted leaps plus exact events.
+    #[allow(clippy::too_many_arguments)]
+    pub fn non_spatial_tau_leap(
+        task: &str,
+        model: &str,
+        output_label: &str,
+        config: TauLeapConfig,
+        steps_run: usize,
+        termination_reason: TerminationReason,
+        signal: WriterStats,
+        step_stats: StepStats,
+        num_species: usize,
+        cutoff: Option<f64>,
+        carrying_capacity: Option<f64>,
+        survivor_tolerance: Option<f64>,
+    ) -> Self {
+        let mut outcome = Self::non_spatial(
+            task,
+            model,
+            output_label,
+            config.max_steps,
+            0.0,
+            0,
+            steps_run,
+            termination_reason,
+            signal,
+            num_species,
+            cutoff,
+            carrying_capacity,
+            survivor_tolerance,
+        );
+        outcome.tau_leap = Some(config);
+        outcome.step_stats = Some(step_stats);
+        outcome
+    }
+
+    /// Record a finished solve on this summary.
+    ///
+    /// Details:
+    /// - Purpose: Sets `start_physical_time` to the initial state's time and
+    ///   `end_physical_time` to `outcome.final_state`'s, then writes
+    ///   `extinctions.json` under `output_path` and stores its summary.
+    pub fn record_run(
+        &mut self,
+        outcome: &SolveOutcome,
+        start_physical_time: f64,
+        output_path: &Path,
+    ) -> Result<()> {
+        self.start_physical_time = Some(start_physical_time);
+        self.end_physical_time = Some(outcome.final_state.physical_time);
+        self.extinctions = Some(save_extinctions(output_path, &outcome.extinctions)?);
+        Ok(())
+    }
 }
 
 pub fn prepare_output_dir(output_path: &Path) -> Result<()> {
@@ -142,15 +337,18 @@ pub fn prepare_output_dir(output_path: &Path) -> Result<()> {
 
     remove_child_dir(output_path, "signal")?;
     remove_child_dir(output_path, "space")?;
+    remove_child_dir(output_path, "resources")?;
 
-    let metadata = output_path.join("metadata.json");
-    if metadata.exists() {
-        remove_file(&metadata).map_err(|e| {
-            Error::new(
-                e.kind(),
-                format!("prepare_output_dir: remove {}: {e}", metadata.display()),
-            )
-        })?;
+    for name in ["metadata.json", "extinctions.json"] {
+        let file = output_path.join(name);
+        if file.exists() {
+            remove_file(&file).map_err(|e| {
+                Error::new(
+                    e.kind(),
+                    format!("prepare_output_dir: remove {}: {e}", file.display()),
+                )
+            })?;
+        }
     }
 
     Ok(())
@@ -186,41 +384,77 @@ fn remove_child_dir(output_path: &Path, name: &str) -> Result<()> {
 }
 
 pub fn save_metadata(output_path: &Path, outcome: &TaskOutcome) -> Result<()> {
-    create_dir_all(output_path).map_err(|e| {
-        Error::new(
-            e.kind(),
-            format!("save_metadata: create dir {}: {e}", output_path.display()),
-        )
-    })?;
+    save_json(&output_path.join("metadata.json"), outcome, "save_metadata")
+}
+
+/// Write `extinctions.json` beside `metadata.json` and return its summary.
+pub fn save_extinctions(
+    output_path: &Path,
+    records: &[ExtinctionRecord],
+) -> Result<ExtinctionSummary> {
+    save_json(
+        &output_path.join("extinctions.json"),
+        &records,
+        "save_extinctions",
+    )?;
+    Ok(ExtinctionSummary::from_records(records))
+}
+
+pub fn load_extinctions(path: &Path) -> Result<Vec<ExtinctionRecord>> {
+    load_json(path, "load_extinctions")
+}
 
-    let file_path = output_path.join("metadata.json");
-    let file = File::create(&file_path).map_err(|e| {
+pub fn load_metadata(path: &Path) -> Result<TaskOutcome> {
+    load_json(path, "load_metadata")
+}
+
+/// Write `value` as pretty JSON to `file_path`.
+///
+/// Details:
+/// - Purpose: Shared writer behind every task-level JSON file; creates the
+///   parent directory and prefixes errors with `context`.
+pub(crate) fn save_json<T: Serialize + ?Sized>(
+    file_path: &Path,
+    value: &T,
+    context: &str,
+) -> Result<()> {
+    if let Some(dir) = file_path.parent() {
+        create_dir_all(dir).map_err(|e| {
+            Error::new(
+                e.kind(),
+                format!("{context}: create dir {}: {e}", dir.display()),
+            )
+        })?;
+    }
+
+    let file = File::create(file_path).map_err(|e| {
         Error::new(
             e.kind(),
-            format!("save_metadata: create {}: {e}", file_path.display()),
+            format!("{context}: create {}: {e}", file_path.display()),
         )
     })?;
 
-    serde_json::to_writer_pretty(BufWriter::new(file), outcome).map_err(|e| {
+    serde_json::to_writer_pretty(BufWriter::new(file), value).map_err(|e| {
         Error::new(
             ErrorKind::InvalidData,
-            format!("save_metadata: serialize {}: {e}", file_path.display()),
+            format!("{context}: serialize {}: {e}", file_path.display()),
         )
     })
 }
 
-pub fn load_metadata(path: &Path) -> Result<TaskOutcome> {
-    let raw = read_to_string(path).map_err(|e| {
-        Error::new(
-            e.kind(),
-            format!("load_metadata: read {}: {e}", path.display()),
-        )
-    })?;
+/// Read a JSON file written by `save_json`.
+///
+/// Details:
+/// - Purpose: Shared reader behind every task-level JSON file; prefixes
+///   errors with `context`.
+pub(crate) fn load_json<T: DeserializeOwned>(path: &Path, context: &str) -> Result<T> {
+    let raw = read_to_string(path)
+        .map_err(|e| Error::new(e.kind(), format!("{context}: read {}: {e}", path.display())))?;
 
     serde_json::from_str(&raw).map_err(|e| {
         Error::new(
             ErrorKind::InvalidData,
-            format!("load_metadata: deserialize {}: {e}", path.display()),
+            format!("{context}: deserialize {}: {e}", path.display()),
         )
     })
 }
@@ -228,8 +462,18 @@ pub fn load_metadata(path: &Path) -> Result<TaskOutcome> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::signal::load_signal_series;
+    use crate::solvers::events::{EventCause, EventSchedule, ScheduledEvent};
+    use crate::solvers::non_spatial::noise::NoiseKind;
+    use crate::solvers::non_spatial::sde::{Calculus, SdeScheme};
     use crate::solvers::termination::TerminationConfig;
-    use crate::tasks::replicator_deterministic;
+    use crate::solvers::termination::{
+        AdaptiveFixedPointConfig, SteadyStateConfig, TerminationObservable,
+    };
+    use crate::tasks::{
+        lv_deterministic, lv_forced, lv_holling, lv_invasion, replicator_demographic,
+        replicator_deterministic,
+    };
     use ndarray::Array2;
     use std::fs;
 
@@ -272,10 +516,293 @@ mod tests {
         assert_eq!(loaded.signal.samples, 3);
         assert_eq!(loaded.num_species, 2);
         assert_eq!(loaded.model, "well_mixed_replicator");
+        assert!(loaded.noise.is_none());
+        assert!(loaded.seed.is_none());
         assert!(metadata_path.is_file());
         assert!(output_path.join("signal/1.json").is_file());
         assert!(!output_path.join("space").exists());
 
         let _ = fs::remove_dir_all(output_path);
     }
+
+    #[test]
+    fn noise_task_records_scheme_calculus_and_seed() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_noise_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        let interaction = Array2::zeros((2, 2));
+        replicator_demographic::run(
+            &interaction,
+            None,
+            1e-12,
+            0.05,
+            SdeScheme::StochasticHeun,
+            Calculus::Stratonovich,
+            RngSeed::new(5).replicate(2),
+            0.01,
+            4,
+            2,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+
+        let loaded = load_metadata(&output_path.join("metadata.json")).expect("metadata loads");
+        let noise = loaded.noise.expect("noise recorded");
+        assert!(matches!(
+            noise.kind,
+            NoiseKind::DemographicGaussian { sigma } if sigma == 0.05
+        ));
+        assert_eq!(noise.scheme, SdeScheme::StochasticHeun);
+        assert_eq!(noise.calculus, Calculus::Stratonovich);
+        assert_eq!(loaded.seed, Some(RngSeed::new(5).replicate(2)));
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn invasion_task_records_events_in_signal_and_metadata() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_invasion_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        let steady = TerminationConfig {
+            monoculture: false,
+            survivor_tolerance: None,
+            steady_state: SteadyStateConfig::Adaptive {
+                fixed_point: AdaptiveFixedPointConfig {
+                    tolerance: 1e-6,
+                    min_steps: 100,
+                    min_window: 5,
+                    max_window: 5,
+                    stable_checks_required: 1,
+                },
+                oscillation: None,
+            },
+            observable: TerminationObservable::GlobalState,
+            check_interval: 10,
+        };
+        let events = EventSchedule::new()
+            .event(ScheduledEvent::at_step(50).introduce(1, 0.1))
+            .event(ScheduledEvent::at_steady_state(steady).introduce(2, 0.1));
+        let interaction = Array2::from_diag(&ndarray::array![-1.0, -1.0, -1.0]);
+        let outcome = lv_invasion::run(
+            &interaction,
+            Some(&ndarray::array![1.0, 1.0, 1.0]),
+            1e-9,
+            None,
+            &[0],
+            0.5,
+            &events,
+            0.01,
+            3_000,
+            1_000,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+
+        let loaded = load_metadata(&output_path.join("metadata.json")).expect("metadata loads");
+        let applied = loaded.events.expect("events recorded");
+        assert_eq!(applied.len(), 2);
+        assert_eq!(
+            (applied[0].step, &applied[0].cause),
+            (50, &EventCause::Step)
+        );
+        assert!(matches!(applied[1].cause, EventCause::SteadyState(_)));
+        assert!(applied[1].step > 150);
+        assert_eq!(outcome.signal.samples, 6);
+
+        let series = load_signal_series(&output_path.join("signal/1.json")).expect("signal loads");
+        let marked: Vec<_> = series.samples.iter().filter_map(|s| s.event).collect();
+        assert_eq!(marked, vec![0, 1]);
+        assert_eq!(series.samples[1].time, 50);
+        assert!((series.samples[1].state[1] - 0.1).abs() < 1e-12);
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn forced_task_switches_regimes_and_records_forcing() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_forced_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        // Logistic growth whose carrying capacity doubles at t = 10.
+        let forcing = Forcing::piecewise().regime(10.0, Some(ndarray::array![2.0]), None);
+        lv_forced::run(
+            &ndarray::array![[-1.0]],
+            Some(&ndarray::array![1.0]),
+            &forcing,
+            1e-9,
+            None,
+            0.5,
+            0.01,
+            3_000,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+
+        let loaded = load_metadata(&output_path.join("metadata.json")).expect("metadata loads");
+        let Some(Forcing::Piecewise { regimes }) = loaded.forcing else {
+            panic!("piecewise forcing recorded");
+        };
+        assert_eq!(regimes.len(), 1);
+        assert_eq!(regimes[0].start, 10.0);
+
+        let series = load_signal_series(&output_path.join("signal/1.json")).expect("signal loads");
+        let before = series.samples.iter().find(|s| s.time == 900).unwrap();
+        let after = series.samples.last().unwrap();
+        assert!((before.state[0] - 1.0).abs() < 1e-3);
+        assert!((after.state[0] - 2.0).abs() < 1e-3);
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn holling_task_records_functional_response() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_holling_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        // Logistic prey eaten by a predator with a type II response.
+        let response = FunctionalResponse::holling_ii(ndarray::array![[0.0, 1.0], [1.0, 0.0]]);
+        let outcome = lv_holling::run(
+            &ndarray::array![[-0.1, -1.0], [0.5, 0.0]],
+            Some(&ndarray::array![1.0, -0.2]),
+            &response,
+            1e-9,
+            None,
+            1.0,
+            0.01,
+            2_000,
+            100,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+        assert_eq!(outcome.model, "well_mixed_holling_glv");
+
+        let loaded = load_metadata(&output_path.join("metadata.json")).expect("metadata loads");
+        assert_eq!(loaded.functional_response, Some(response));
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn extinctions_are_logged_beside_metadata_with_step_time_and_abundance() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_extinctions_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        // n_1 is logistic; n_2 decays as e^{-t} and crosses the 1e-3 cutoff
+        // just after t = ln(1000) ≈ 6.91, between two saved samples.
+        let interaction = ndarray::array![[-1.0, 0.0], [0.0, 0.0]];
+        let growth = ndarray::array![1.0, -1.0];
+        let outcome = lv_deterministic::run(
+            &interaction,
+            Some(&growth),
+            1e-3,
+            None,
+            1.0,
+            0.01,
+            1_000,
+            250,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+
+        let records =
+            load_extinctions(&output_path.join("extinctions.json")).expect("extinctions load");
+        assert_eq!(records.len(), 1);
+        let record = records[0];
+        assert_eq!(record.species, 1);
+        assert_eq!(record.step, 691);
+        assert!((record.time - 6.91).abs() < 1e-9);
+        assert!(record.abundance >= 1e-3 && record.abundance < 1.01e-3);
+
+        let loaded = load_metadata(&output_path.join("metadata.json")).expect("metadata loads");
+        let summary = loaded.extinctions.expect("summary recorded");
+        assert_eq!(summary, outcome.extinctions.expect("summary returned"));
+        assert_eq!(summary.extinctions, 1);
+        assert_eq!(summary.extinct_species, 1);
+        assert_eq!(summary.first_time, Some(record.time));
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+
+    #[test]
+    fn physical_time_is_recorded_and_older_output_still_loads() {
+        let output_path =
+            std::env::temp_dir().join(format!("glv_metadata_physical_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&output_path);
+
+        let interaction = Array2::zeros((2, 2));
+        replicator_deterministic::run(
+            &interaction,
+            None,
+            1e-12,
+            0.25,
+            4,
+            2,
+            &output_path,
+            None,
+            TerminationConfig::disabled(),
+        )
+        .expect("task succeeds");
+
+        let signal_path = output_path.join("signal/1.json");
+        let series = load_signal_series(&signal_path).expect("signal loads");
+        let samples: Vec<_> = series
+            .samples
+            .iter()
+            .map(|s| (s.time, s.physical_time))
+            .collect();
+        assert_eq!(
+            samples,
+            vec![(0, Some(0.0)), (2, Some(0.5)), (4, Some(1.0))]
+        );
+        let metadata_path = output_path.join("metadata.json");
+        let loaded = load_metadata(&metadata_path).expect("metadata loads");
+        assert_eq!(loaded.start_physical_time, Some(0.0));
+        assert_eq!(loaded.end_physical_time, Some(1.0));
+
+        // Output written before physical time was recorded has no such keys.
+        let mut signal: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&signal_path).expect("read signal"))
+                .expect("signal json");
+        for sample in signal["samples"].as_array_mut().expect("samples") {
+            sample
+                .as_object_mut()
+                .expect("sample")
+                .remove("physical_time");
+        }
+        fs::write(&signal_path, signal.to_string()).expect("write signal");
+        let mut metadata: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&metadata_path).expect("read metadata"))
+                .expect("metadata json");
+        let fields = metadata.as_object_mut().expect("metadata");
+        fields.remove("start_physical_time");
+        fields.remove("end_physical_time");
+        fs::write(&metadata_path, metadata.to_string()).expect("write metadata");
+
+        let series = load_signal_series(&signal_path).expect("legacy signal loads");
+        assert!(series.samples.iter().all(|s| s.physical_time.is_none()));
+        assert_eq!(series.samples[2].time, 4);
+        let loaded = load_metadata(&metadata_path).expect("legacy metadata loads");
+        assert_eq!(loaded.start_physical_time, None);
+        assert_eq!(loaded.end_physical_time, None);
+        assert_eq!(loaded.end_time, 4);
+
+        let _ = fs::remove_dir_all(output_path);
+    }
 }
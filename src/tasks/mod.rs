@@ -4,14 +4,27 @@ Task module surface.
 Purpose:
     `tasks` exposes experiment-level entry points. Ready tasks wire
     non-spatial and spatial solver stacks into total-step runs whose signal and
-    space output streams are automatically chunked.
+    space output streams are automatically chunked. `ensemble` fans
+    replicates of one task out over rayon; `sweep` runs one task over a
+    parameter grid.
 */
 
+pub mod ensemble;
 pub mod metadata;
+pub mod replicator_adaptive;
 pub mod replicator_demographic;
 pub mod replicator_deterministic;
 pub mod replicator_diffusive_deterministic;
+pub mod replicator_finite_population;
+pub mod replicator_mutator;
+pub mod sweep;
 
+pub mod lv_adaptive;
 pub mod lv_demographic;
 pub mod lv_deterministic;
 pub mod lv_diffusive_deterministic;
+pub mod lv_forced;
+pub mod lv_gillespie;
+pub mod lv_holling;
+pub mod lv_invasion;
+pub mod lv_tau_leaping;
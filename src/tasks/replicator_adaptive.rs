@@ -0,0 +1,90 @@
+/*!
+Adaptive-step replicator task.
+
+Purpose:
+    This task wires a well-mixed frequency initial condition into the adaptive
+    Dormand–Prince replicator solver and writes automatically sized JSON
+    time-series chunks on a regular physical-time grid.
+*/
+
+use std::io::Result;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+
+use ndarray::{Array1, Array2};
+
+use crate::Mode;
+use crate::solvers::non_spatial::rk45::{AdaptiveConfig, solve_adaptive_with_termination};
+use crate::solvers::termination::TerminationConfig;
+use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
+use crate::utils::create_well_mixed_gs;
+
+/// Run one adaptive trajectory and let the signal writer chunk output files by size.
+///
+/// Details:
+/// - Purpose: Runs deterministic replicator dynamics up to `config.t_end`
+///   with embedded RK45 error control.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `cutoff`: Frequency cutoff used by state sanitization.
+///   - `config`: Tolerances, step bounds, and physical-time save grid.
+///   - `output_path`: Root output directory.
+///   - `progress_counter`: Optional shared progress counter.
+///   - `termination`: Explicit early-termination behavior.
+pub fn run(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    cutoff: f64,                            // cutoff
+    config: AdaptiveConfig,                 // step control and time grid
+    output_path: &Path,                     // root output dir
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<TaskOutcome> {
+    let d = interaction_matrix.nrows();
+    debug_assert_eq!(
+        interaction_matrix.ncols(),
+        d,
+        "interaction_matrix must be square"
+    );
+    if let Some(g) = growth_vector {
+        debug_assert_eq!(g.len(), d, "growth_vector length must match V");
+    }
+
+    // Initial condition: well-mixed uniform simplex (ν_i = 1/d).
+    let mode = Mode::Frequency {
+        cutoff: Some(cutoff),
+    };
+    let gs = create_well_mixed_gs(mode, d, None);
+    prepare_output_dir(output_path)?;
+
+    let start_physical_time = gs.physical_time;
+    let outcome = solve_adaptive_with_termination(
+        gs,                 // initial state
+        interaction_matrix, // V
+        growth_vector,      // g
+        config,             // step control and time grid
+        output_path,        // output target
+        progress_counter,
+        termination,
+    )?;
+
+    let mut task_outcome = TaskOutcome::non_spatial_adaptive(
+        "replicator_adaptive",
+        "well_mixed_replicator",
+        &output_label(output_path),
+        config,
+        outcome.steps_run,
+        outcome.reason,
+        outcome.signal_stats,
+        outcome.step_stats.unwrap_or_default(),
+        d,
+        Some(cutoff),
+        None,
+        termination.survivor_tolerance,
+    );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
+    save_metadata(output_path, &task_outcome)?;
+
+    Ok(task_outcome)
+}
@@ -2,9 +2,11 @@
 Demographic-noise replicator task.
 
 Purpose:
-    This task wires a well-mixed frequency initial condition into the RK4
-    replicator solver with demographic Gaussian noise after each deterministic
-    step and writes automatically sized JSON time-series chunks.
+    This task wires a well-mixed frequency initial condition into the
+    replicator solver with demographic Gaussian noise, integrated by the chosen
+    SDE scheme and calculus, and writes automatically sized JSON time-series
+    chunks. The noise configuration and seed are recorded in
+    `metadata.json`.
 */
 
 use std::io::Result;
@@ -16,6 +18,8 @@ use ndarray::{Array1, Array2};
 use crate::Mode;
 use crate::solvers::non_spatial::noise::Noise;
 use crate::solvers::non_spatial::rk4::solve_with_termination;
+use crate::solvers::non_spatial::sde::{Calculus, SdeScheme};
+use crate::solvers::rng::RngSeed;
 use crate::solvers::termination::TerminationConfig;
 use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
 use crate::utils::create_well_mixed_gs;
@@ -29,17 +33,24 @@ use crate::utils::create_well_mixed_gs;
 ///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
 ///   - `cutoff`: Frequency cutoff used by state sanitization.
 ///   - `sigma`: Demographic noise strength.
+///   - `sde_scheme`: Noise scheme; `PostStepSplitting` keeps the RK4 step.
+///   - `calculus`: Itô or Stratonovich reading of the noise term.
+///   - `seed`: Random stream; recorded in `metadata.json` for replay.
 ///   - `dt`: Step size.
 ///   - `total_steps`: Total solver steps to execute.
 ///   - `save_interval`: Save every Nth step.
 ///   - `output_path`: Root output directory.
 ///   - `progress_counter`: Optional shared progress counter.
 ///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     interaction_matrix: &Array2<f64>,       // V
     growth_vector: Option<&Array1<f64>>,    // g override
     cutoff: f64,                            // cutoff
     sigma: f64,                             // demographic noise strength
+    sde_scheme: SdeScheme,                  // noise scheme
+    calculus: Calculus,                     // noise calculus
+    seed: RngSeed,                          // random stream key and index
     dt: f64,                                // step size
     total_steps: usize,                     // total solver steps
     save_interval: usize,                   // save every N steps
@@ -62,22 +73,27 @@ pub fn run(
         cutoff: Some(cutoff),
     };
     let gs = create_well_mixed_gs(mode, d, None);
+    let noise = Noise::demographic_gaussian(sigma)
+        .with_scheme(sde_scheme)
+        .with_calculus(calculus);
     prepare_output_dir(output_path)?;
 
+    let start_physical_time = gs.physical_time;
     let outcome = solve_with_termination(
-        gs,                                 // initial state
-        interaction_matrix,                 // V
-        growth_vector,                      // g
-        Noise::demographic_gaussian(sigma), // demographic noise
-        dt,                                 // step size
-        total_steps,                        // steps
-        save_interval,                      // save every N steps
-        output_path,                        // output target
+        gs,                 // initial state
+        interaction_matrix, // V
+        growth_vector,      // g
+        noise,              // demographic noise
+        seed,               // random stream
+        dt,                 // step size
+        total_steps,        // steps
+        save_interval,      // save every N steps
+        output_path,        // output target
         progress_counter,
         termination,
     )?;
 
-    let task_outcome = TaskOutcome::non_spatial(
+    let mut task_outcome = TaskOutcome::non_spatial(
         "replicator_demographic",
         "well_mixed_replicator",
         &output_label(output_path),
@@ -89,8 +105,12 @@ pub fn run(
         outcome.signal_stats,
         d,
         Some(cutoff),
+        None,
         termination.survivor_tolerance,
     );
+    task_outcome.noise = Some(noise);
+    task_outcome.seed = Some(seed);
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
     save_metadata(output_path, &task_outcome)?;
 
     Ok(task_outcome)
@@ -15,6 +15,7 @@ use ndarray::{Array1, Array2};
 use crate::Mode;
 use crate::solvers::non_spatial::noise::Noise;
 use crate::solvers::non_spatial::rk4::solve_with_termination;
+use crate::solvers::rng::RngSeed;
 use crate::solvers::termination::TerminationConfig;
 use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
 use crate::utils::create_well_mixed_gs;
@@ -33,6 +34,7 @@ use crate::utils::create_well_mixed_gs;
 ///   - `output_path`: Root output directory.
 ///   - `progress_counter`: Optional shared progress counter.
 ///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     interaction_matrix: &Array2<f64>,       // V
     growth_vector: Option<&Array1<f64>>,    // g override
@@ -61,11 +63,13 @@ pub fn run(
     let gs = create_well_mixed_gs(mode, d, None);
     prepare_output_dir(output_path)?;
 
+    let start_physical_time = gs.physical_time;
     let outcome = solve_with_termination(
         gs,                 // initial state
         interaction_matrix, // V
         growth_vector,      // g
         Noise::none(),      // deterministic run
+        RngSeed::new(0),    // unused without noise
         dt,                 // step size
         total_steps,        // steps
         save_interval,      // save every N steps
@@ -74,7 +78,7 @@ pub fn run(
         termination,
     )?;
 
-    let task_outcome = TaskOutcome::non_spatial(
+    let mut task_outcome = TaskOutcome::non_spatial(
         "replicator_deterministic",
         "well_mixed_replicator",
         &output_label(output_path),
@@ -86,8 +90,10 @@ pub fn run(
         outcome.signal_stats,
         d,
         Some(cutoff),
+        None,
         termination.survivor_tolerance,
     );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
     save_metadata(output_path, &task_outcome)?;
 
     Ok(task_outcome)
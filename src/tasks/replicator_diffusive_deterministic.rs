@@ -35,6 +35,7 @@ use crate::utils::create_uniform_spatial_frequency_gs;
 ///   - `output_path`: Root output directory.
 ///   - `progress_counter`: Optional shared progress counter.
 ///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     interaction_matrix: &Array2<f64>,       // V
     growth_vector: Option<&Array1<f64>>,    // g override
@@ -71,6 +72,7 @@ pub fn run(
     let gs = create_uniform_spatial_frequency_gs(Some(cutoff), spatial_shape, d);
     prepare_output_dir(output_path)?;
 
+    let start_physical_time = gs.physical_time;
     let outcome = solve_replicator_with_termination(
         gs,                 // initial state
         interaction_matrix, // V
@@ -85,7 +87,7 @@ pub fn run(
         termination,
     )?;
 
-    let task_outcome = TaskOutcome::spatial(
+    let mut task_outcome = TaskOutcome::spatial(
         "replicator_diffusive_deterministic",
         "spatial_replicator",
         &output_label(output_path),
@@ -102,6 +104,7 @@ pub fn run(
         None,
         termination.survivor_tolerance,
     );
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
     save_metadata(output_path, &task_outcome)?;
 
     Ok(task_outcome)
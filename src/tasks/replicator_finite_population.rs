@@ -0,0 +1,107 @@
+/*!
+Finite-population replicator task.
+
+Purpose:
+    This task wires a well-mixed frequency initial condition into the
+    Wright–Fisher or Moran finite-population solver and writes automatically
+    sized JSON time-series chunks. The update rule, population size, and seed
+    are recorded in `metadata.json`.
+*/
+
+use std::io::Result;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+
+use ndarray::{Array1, Array2};
+
+use crate::Mode;
+use crate::solvers::non_spatial::finite_population::{
+    FinitePopulation, solve_finite_population_with_termination,
+};
+use crate::solvers::rng::RngSeed;
+use crate::solvers::termination::TerminationConfig;
+use crate::tasks::metadata::{TaskOutcome, output_label, prepare_output_dir, save_metadata};
+use crate::utils::create_well_mixed_gs;
+
+/// Run one trajectory and let the signal writer chunk output files by size.
+///
+/// Details:
+/// - Purpose: Runs finite-population replicator dynamics for up to
+///   `total_steps` steps; fixation stops the run early.
+/// - Parameters:
+///   - `interaction_matrix`: Square interaction matrix `V`.
+///   - `growth_vector`: Optional growth vector `g`; defaults to zero.
+///   - `finite_population`: Wright–Fisher or Moran update and size `N`.
+///   - `seed`: Random stream; recorded in `metadata.json` for replay.
+///   - `dt`: Generation time.
+///   - `total_steps`: Total solver steps to execute.
+///   - `save_interval`: Save every Nth step.
+///   - `output_path`: Root output directory.
+///   - `progress_counter`: Optional shared progress counter.
+///   - `termination`: Explicit early-termination behavior.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    interaction_matrix: &Array2<f64>,       // V
+    growth_vector: Option<&Array1<f64>>,    // g override
+    finite_population: FinitePopulation,    // update rule and N
+    seed: RngSeed,                          // random stream key and index
+    dt: f64,                                // generation time
+    total_steps: usize,                     // total solver steps
+    save_interval: usize,                   // save every N steps
+    output_path: &Path,                     // root output dir
+    progress_counter: Option<&AtomicUsize>, // optional progress counter
+    termination: TerminationConfig,         // explicit termination behavior
+) -> Result<TaskOutcome> {
+    let d = interaction_matrix.nrows();
+    debug_assert_eq!(
+        interaction_matrix.ncols(),
+        d,
+        "interaction_matrix must be square"
+    );
+    if let Some(g) = growth_vector {
+        debug_assert_eq!(g.len(), d, "growth_vector length must match V");
+    }
+
+    // Initial condition: well-mixed uniform simplex (ν_i = 1/d), apportioned
+    // onto N individuals by the solver.
+    let mode = Mode::Frequency { cutoff: None };
+    let gs = create_well_mixed_gs(mode, d, None);
+    prepare_output_dir(output_path)?;
+
+    let start_physical_time = gs.physical_time;
+    let outcome = solve_finite_population_with_termination(
+        gs,                 // initial state
+        interaction_matrix, // V
+        growth_vector,      // g
+        finite_population,  // update rule and N
+        seed,               // random stream
+        dt,                 // generation time
+        total_steps,        // steps
+        save_interval,      // save every N steps
+        output_path,        // output target
+        progress_counter,
+        termination,
+    )?;
+
+    let mut task_outcome = TaskOutcome::non_spatial(
+        "replicator_finite_population",
+        "well_mixed_replicator",
+        &output_label(output_path),
+        total_steps,
+        dt,
+        save_interval,
+        outcome.steps_run,
+        outcome.reason,
+        outcome.signal_stats,
+        d,
+        None,
+        None,
+        termination.survivor_tolerance,
+    );
+    task_outcome.finite_population = Some(finite_population);
+    task_outcome.seed = Some(seed);
+    task_outcome.record_run(&outcome, start_physical_time, output_path)?;
+    save_metadata(output_path, &task_outcome)?;
+
+    Ok(task_outcome)
+}
@@ -0,0 +1,351 @@
+/*!
+Parameter sweep task.
+
+Purpose:
+    This task runs one task runner over every point of a declarative parameter
+    grid in parallel. Point `i` writes its task output under
+    `output/<label>/point_<i>/` together with a `params.json` that records its
+    parameter values. The sweep root gets a `sweep.json` index that maps each
+    parameter tuple to its output directory and `TaskOutcome`.
+
+Resume contract:
+    A point is complete when its directory holds a `metadata.json` and a
+    `params.json` equal to the point's parameters. Task runners remove
+    `metadata.json` before stepping and rewrite it last, and `params.json` is
+    written after the task returns, so interrupted or re-parameterized points
+    never count as complete. With `skip_completed`, complete points are not
+    rerun; their stored metadata is indexed instead.
+*/
+
+use std::collections::BTreeMap;
+use std::fs::create_dir_all;
+use std::io::{Error, ErrorKind, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::tasks::metadata::{TaskOutcome, load_json, load_metadata, output_label, save_json};
+
+/// One named axis of a sweep grid.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SweepAxis {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+/// Cartesian product of named parameter axes.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SweepGrid {
+    pub axes: Vec<SweepAxis>,
+}
+
+impl SweepGrid {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an axis; later axes vary fastest in point order.
+    pub fn axis(mut self, name: &str, values: &[f64]) -> Self {
+        self.axes.push(SweepAxis {
+            name: name.to_owned(),
+            values: values.to_vec(),
+        });
+        self
+    }
+
+    /// Number of grid points.
+    pub fn len(&self) -> usize {
+        if self.axes.is_empty() {
+            0
+        } else {
+            self.axes.iter().map(|axis| axis.values.len()).product()
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Enumerate grid points in row-major order.
+    pub fn points(&self) -> Vec<SweepPoint> {
+        (0..self.len())
+            .map(|index| {
+                let mut rest = index;
+                let mut params = BTreeMap::new();
+                for axis in self.axes.iter().rev() {
+                    let n = axis.values.len();
+                    params.insert(axis.name.clone(), axis.values[rest % n]);
+                    rest /= n;
+                }
+                SweepPoint { index, params }
+            })
+            .collect()
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        for (k, axis) in self.axes.iter().enumerate() {
+            if self.axes[..k].iter().any(|other| other.name == axis.name) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("sweep axis {} appears twice", axis.name),
+                ));
+            }
+            if axis.values.iter().any(|v| !v.is_finite()) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("sweep axis {} has a non-finite value", axis.name),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One grid point: its row-major index and parameter values.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SweepPoint {
+    pub index: usize,
+    pub params: BTreeMap<String, f64>,
+}
+
+impl SweepPoint {
+    /// Value of parameter `name`; missing names are `InvalidInput`.
+    pub fn get(&self, name: &str) -> Result<f64> {
+        self.params.get(name).copied().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("sweep point has no parameter {name}"),
+            )
+        })
+    }
+}
+
+/// One indexed point in `sweep.json`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SweepEntry {
+    pub index: usize,
+    pub params: BTreeMap<String, f64>,
+    pub output_dir: String,
+    pub skipped: bool,
+    pub outcome: TaskOutcome,
+}
+
+/// Sweep index persisted as `sweep.json` at the sweep root.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SweepIndex {
+    pub task: String,
+    pub output_label: String,
+    pub grid: SweepGrid,
+    pub num_points: usize,
+    pub num_run: usize,
+    pub num_skipped: usize,
+    pub points: Vec<SweepEntry>,
+}
+
+/// Output directory of point `index` under the sweep root.
+pub fn point_dir(output_path: &Path, index: usize) -> PathBuf {
+    output_path.join(format!("point_{index}"))
+}
+
+/// Run one task over every point of a parameter grid in parallel.
+///
+/// Details:
+/// - Purpose: Calls `point_run(point, point_dir)` for every grid point on the
+///   rayon pool, records `params.json` per point, then writes `sweep.json`.
+/// - Parameters:
+///   - `task`: Task name recorded in `sweep.json`.
+///   - `grid`: Declarative parameter grid.
+///   - `skip_completed`: Reuse points whose stored parameters match.
+///   - `output_path`: Sweep root directory.
+///   - `progress_counter`: Optional shared counter of finished points.
+///   - `point_run`: Runs one point into the given directory.
+pub fn run<F>(
+    task: &str,                             // task name
+    grid: &SweepGrid,                       // parameter grid
+    skip_completed: bool,                   // reuse completed points
+    output_path: &Path,                     // sweep root dir
+    progress_counter: Option<&AtomicUsize>, // finished points
+    point_run: F,                           // one point run
+) -> Result<SweepIndex>
+where
+    F: Fn(&SweepPoint, &Path) -> Result<TaskOutcome> + Sync,
+{
+    grid.validate()?;
+    if output_path.is_file() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("output path {} is a file", output_path.display()),
+        ));
+    }
+    create_dir_all(output_path).map_err(|e| {
+        Error::new(
+            e.kind(),
+            format!("sweep: create dir {}: {e}", output_path.display()),
+        )
+    })?;
+    if let Some(counter) = progress_counter {
+        counter.store(0, Ordering::Relaxed);
+    }
+
+    let points = grid
+        .points()
+        .into_par_iter()
+        .map(|point| {
+            let dir = point_dir(output_path, point.index);
+            let stored = if skip_completed {
+                completed_outcome(&dir, &point)
+            } else {
+                None
+            };
+            let skipped = stored.is_some();
+            let outcome = match stored {
+                Some(outcome) => outcome,
+                None => {
+                    let outcome = point_run(&point, &dir)?;
+                    save_json(&dir.join("params.json"), &point.params, "sweep")?;
+                    outcome
+                }
+            };
+
+            if let Some(counter) = progress_counter {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(SweepEntry {
+                index: point.index,
+                output_dir: output_label(&dir),
+                params: point.params,
+                skipped,
+                outcome,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let num_skipped = points.iter().filter(|entry| entry.skipped).count();
+    let index = SweepIndex {
+        task: task.to_owned(),
+        output_label: output_label(output_path),
+        grid: grid.clone(),
+        num_points: points.len(),
+        num_run: points.len() - num_skipped,
+        num_skipped,
+        points,
+    };
+    save_json(&output_path.join("sweep.json"), &index, "sweep")?;
+
+    Ok(index)
+}
+
+pub fn load_sweep_index(path: &Path) -> Result<SweepIndex> {
+    load_json(path, "load_sweep_index")
+}
+
+fn completed_outcome(dir: &Path, point: &SweepPoint) -> Option<TaskOutcome> {
+    let params: BTreeMap<String, f64> = load_json(&dir.join("params.json"), "sweep").ok()?;
+    if params != point.params {
+        return None;
+    }
+    load_metadata(&dir.join("metadata.json")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::termination::TerminationConfig;
+    use crate::tasks::replicator_deterministic;
+    use ndarray::array;
+    use std::fs;
+
+    fn temp_output_dir(test_name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("glv_sweep_{test_name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn grid_enumerates_the_cartesian_product_row_major() {
+        let grid = SweepGrid::new()
+            .axis("dt", &[0.1, 0.2])
+            .axis("sigma", &[0.0, 0.5, 1.0]);
+        let points = grid.points();
+
+        assert_eq!(grid.len(), 6);
+        assert_eq!(points[0].get("dt").unwrap(), 0.1);
+        assert_eq!(points[2].get("sigma").unwrap(), 1.0);
+        assert_eq!(points[3].get("dt").unwrap(), 0.2);
+        assert_eq!(points[3].get("sigma").unwrap(), 0.0);
+        assert_eq!(
+            points[1].get("cutoff").unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert!(SweepGrid::new().is_empty());
+        assert!(
+            SweepGrid::new()
+                .axis("a", &[1.0])
+                .axis("a", &[2.0])
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sweep_indexes_points_and_skips_completed_ones() {
+        let output_path = temp_output_dir("replicator");
+        let interaction_matrix = array![[0.0, 1.0], [-1.0, 0.0]];
+        let calls = AtomicUsize::new(0);
+        let sweep = |grid: &SweepGrid| {
+            run(
+                "replicator_deterministic",
+                grid,
+                true,
+                &output_path,
+                None,
+                |point, dir| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    let scaled = &interaction_matrix * point.get("v_scale")?;
+                    replicator_deterministic::run(
+                        &scaled,
+                        None,
+                        1e-9,
+                        point.get("dt")?,
+                        20,
+                        10,
+                        dir,
+                        None,
+                        TerminationConfig::disabled(),
+                    )
+                },
+            )
+            .expect("sweep succeeds")
+        };
+
+        let grid = SweepGrid::new()
+            .axis("v_scale", &[0.5, 1.0])
+            .axis("dt", &[0.01, 0.02]);
+        let index = sweep(&grid);
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+        assert_eq!(index.num_run, 4);
+        assert_eq!(index.points[3].output_dir, "point_3");
+        assert_eq!(index.points[3].params["dt"], 0.02);
+        assert!(point_dir(&output_path, 3).join("signal/1.json").is_file());
+
+        // Extending the slowest axis keeps existing indices and parameters.
+        let grid = SweepGrid::new()
+            .axis("v_scale", &[0.5, 1.0, 2.0])
+            .axis("dt", &[0.01, 0.02]);
+        let index = sweep(&grid);
+        assert_eq!(calls.load(Ordering::Relaxed), 6);
+        assert_eq!(index.num_skipped, 4);
+        assert!(index.points[1].skipped && !index.points[5].skipped);
+
+        let loaded = load_sweep_index(&output_path.join("sweep.json")).expect("index loads");
+        assert_eq!(loaded.num_points, 6);
+        assert_eq!(loaded.points[4].outcome.steps_run, 20);
+
+        let _ = fs::remove_dir_all(output_path);
+    }
+}
@@ -7,7 +7,7 @@ Purpose:
 */
 
 use crate::{Mode, Scalar, SystemState};
-use ndarray::{Array1, ArrayD, IxDyn};
+use ndarray::{Array1, Array2, ArrayD, IxDyn};
 
 /// Create a well-mixed (spatially uniform / no-grid) `SystemState<T>` at time 0.
 ///
@@ -104,3 +104,27 @@ pub fn create_uniform_spatial_population_gs(
 
     SystemState::from_arrays(mode, 0, Array1::zeros(num_taxa), Some(space))
 }
+
+/// Create the uniform point-mutation matrix `Q` for `num_taxa` types.
+///
+/// Details:
+/// - Purpose: Builds the row-stochastic mutation matrix with `Q_ii = 1 - mu`
+///   and `Q_ij = mu / (num_taxa - 1)` used by replicator–mutator runs.
+///   Zero types give an empty matrix and one type gives `[[1]]`.
+/// - Parameters:
+///   - `num_taxa`: Number of types.
+///   - `mu`: Total mutation probability per offspring, in `[0, 1]`. Values
+///     outside that range produce negative entries, which the
+///     replicator–mutator solvers reject with `ErrorKind::InvalidInput`.
+pub fn uniform_mutation_matrix(num_taxa: usize, mu: f64) -> Array2<f64> {
+    match num_taxa {
+        0 => return Array2::zeros((0, 0)),
+        1 => return Array2::ones((1, 1)),
+        _ => {}
+    }
+
+    let off_diagonal = mu / (num_taxa - 1) as f64;
+    Array2::from_shape_fn((num_taxa, num_taxa), |(i, j)| {
+        if i == j { 1.0 - mu } else { off_diagonal }
+    })
+}